@@ -0,0 +1,262 @@
+//! Counts heap allocations per parse and part, to find days that allocate heavily
+//!
+//! Counting needs the binary to register [CountingAllocator] as its global allocator:
+//!
+//! ```
+//! #[global_allocator]
+//! static ALLOCATOR: aoc_runner::alloc::CountingAllocator = aoc_runner::alloc::CountingAllocator;
+//! ```
+//!
+//! It only counts once [enable] was called, e.g. by [AllocAnalyzer::new]. The counters are shared
+//! by all threads, so days that run in parallel count each other's allocations.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::BTreeMap,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering::Relaxed},
+};
+
+use crate::{Analyzer, ParallelAnalyzer, PartResult};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The [System] allocator, counting allocations, allocated bytes and live bytes once [enable]d
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record(size: usize) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        BYTES.fetch_add(size, Relaxed);
+        let live = LIVE.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(live, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && ENABLED.load(Relaxed) {
+            Self::record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && ENABLED.load(Relaxed) {
+            Self::record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Relaxed) {
+            // Memory allocated before counting was enabled must not underflow the live bytes
+            let _ = LIVE.fetch_update(Relaxed, Relaxed, |live| {
+                Some(live.saturating_sub(layout.size()))
+            });
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && ENABLED.load(Relaxed) {
+            let _ = LIVE.fetch_update(Relaxed, Relaxed, |live| {
+                Some(live.saturating_sub(layout.size()))
+            });
+            Self::record(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting allocations of a registered [CountingAllocator]
+pub fn enable() {
+    ENABLED.store(true, Relaxed);
+}
+
+/// What a step allocated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations
+    pub allocations: usize,
+    /// Bytes requested by all allocations
+    pub bytes: usize,
+    /// Most bytes that were live at once, on top of the ones live before the step
+    pub peak: usize,
+}
+
+impl AllocStats {
+    /// Adds up the allocations of consecutive steps, whose peak is the highest of theirs
+    pub fn combine(self, other: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations + other.allocations,
+            bytes: self.bytes + other.bytes,
+            peak: self.peak.max(other.peak),
+        }
+    }
+}
+
+/// The counters at the start of a step
+#[derive(Debug, Clone, Copy)]
+struct Start {
+    allocations: usize,
+    bytes: usize,
+    live: usize,
+}
+
+impl Start {
+    fn now() -> Self {
+        let live = LIVE.load(Relaxed);
+        PEAK.store(live, Relaxed);
+        Start {
+            allocations: ALLOCATIONS.load(Relaxed),
+            bytes: BYTES.load(Relaxed),
+            live,
+        }
+    }
+
+    fn elapsed(self) -> AllocStats {
+        AllocStats {
+            allocations: ALLOCATIONS.load(Relaxed).saturating_sub(self.allocations),
+            bytes: BYTES.load(Relaxed).saturating_sub(self.bytes),
+            peak: PEAK.load(Relaxed).saturating_sub(self.live),
+        }
+    }
+}
+
+/// Records the [AllocStats] of every parse and part, of their last run
+///
+/// Steps are keyed by (day, part), where `part` is `None` for parsing. Nothing is printed; see
+/// [TimeAnalyzer::count_allocations][crate::TimeAnalyzer::count_allocations] for a table.
+#[derive(Debug, Default)]
+pub struct AllocAnalyzer {
+    start: Option<Start>,
+    steps: BTreeMap<(usize, Option<usize>), AllocStats>,
+}
+
+impl AllocAnalyzer {
+    /// Creates an analyzer and [enable]s counting
+    pub fn new() -> Self {
+        enable();
+        AllocAnalyzer::default()
+    }
+
+    /// The allocations of parsing (`part` is `None`) or a part, if it ran
+    pub fn get(&self, day: usize, part: Option<usize>) -> Option<AllocStats> {
+        self.steps.get(&(day, part)).copied()
+    }
+
+    /// The combined allocations of all steps of a day
+    pub fn day(&self, day: usize) -> AllocStats {
+        self.steps
+            .range((day, None)..=(day, Some(usize::MAX)))
+            .map(|(_, stats)| *stats)
+            .fold(AllocStats::default(), AllocStats::combine)
+    }
+
+    /// The combined allocations of all days
+    pub fn total(&self) -> AllocStats {
+        self.steps
+            .values()
+            .copied()
+            .fold(AllocStats::default(), AllocStats::combine)
+    }
+
+    fn after_step(&mut self, day: usize, part: Option<usize>) {
+        if let Some(start) = self.start.take() {
+            self.steps.insert((day, part), start.elapsed());
+        }
+    }
+}
+
+impl Analyzer for AllocAnalyzer {
+    fn before_parse(&mut self, _day: usize) {
+        self.start = Some(Start::now());
+    }
+
+    fn after_parse(&mut self, day: usize) {
+        self.after_step(day, None);
+    }
+
+    fn before_part(&mut self, _day: usize, _part: usize) {
+        self.start = Some(Start::now());
+    }
+
+    fn after_part(&mut self, day: usize, part: usize, _result: Option<&PartResult>) {
+        self.after_step(day, Some(part));
+    }
+}
+
+impl ParallelAnalyzer for AllocAnalyzer {
+    fn fork(&self) -> Self {
+        AllocAnalyzer::default()
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.steps.extend(other.steps);
+    }
+}
+
+/// Formats a number of bytes in its largest binary unit, e.g. `12KiB`
+pub(crate) fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024 && unit + 1 < UNITS.len() {
+        value /= 1024;
+        unit += 1;
+    }
+    format!("{}{}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn count_allocations() {
+        let mut analyzer = AllocAnalyzer::new();
+        analyzer.before_part(1, 1);
+        let buffer = std::hint::black_box(vec![0u8; 1000]);
+        analyzer.after_part(1, 1, None);
+        drop(buffer);
+
+        // Tests running on other threads may allocate in between
+        let stats = analyzer.get(1, Some(1)).unwrap();
+        assert!(stats.allocations >= 1);
+        assert!(stats.bytes >= 1000);
+        assert_eq!(analyzer.get(1, None), None);
+    }
+
+    #[test]
+    fn combine_steps() {
+        let mut analyzer = AllocAnalyzer::default();
+        let stats = |allocations, bytes, peak| AllocStats {
+            allocations,
+            bytes,
+            peak,
+        };
+        analyzer.steps.insert((1, None), stats(2, 100, 80));
+        analyzer.steps.insert((1, Some(1)), stats(3, 50, 120));
+        analyzer.steps.insert((2, Some(2)), stats(1, 10, 10));
+        assert_eq!(analyzer.day(1), stats(5, 150, 120));
+        assert_eq!(analyzer.day(3), AllocStats::default());
+        assert_eq!(analyzer.total(), stats(6, 160, 120));
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(3 * 1024 + 1), "3KiB");
+        assert_eq!(format_bytes(5 << 30), "5GiB");
+    }
+}
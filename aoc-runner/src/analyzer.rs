@@ -1,4 +1,21 @@
-use std::{time::{Instant, Duration}, collections::BTreeMap, fmt::Display};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Debug, Display},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc::{format_bytes, AllocAnalyzer, AllocStats},
+    baseline::{self, Baseline, Regression},
+    Answer, ParallelAnalyzer,
+};
+
+/// Marks the start of the timings table inside a Markdown file
+pub const MARKDOWN_START_MARKER: &str = "<!-- timings:start -->";
+
+/// Marks the end of the timings table inside a Markdown file
+pub const MARKDOWN_END_MARKER: &str = "<!-- timings:end -->";
 
 /// Scaffold metr an AoC runner
 pub trait Analyzer {
@@ -8,6 +25,12 @@ pub trait Analyzer {
     /// Called after running all puzzles
     fn after_all(&mut self) {}
 
+    /// Called before running a [Year][crate::Year]'s puzzles, before `before_all`
+    fn before_year(&mut self, _year: usize) {}
+
+    /// Called after running a [Year][crate::Year]'s puzzles, after `after_all`
+    fn after_year(&mut self, _year: usize) {}
+
     /// Called before running a day's puzzle
     fn before_day(&mut self, _day: usize) {}
 
@@ -24,7 +47,215 @@ pub trait Analyzer {
     fn before_part(&mut self, _day: usize, _part: usize) {}
 
     /// Called after running a puzle's part
-    fn after_part(&mut self, _day: usize, _part: usize) {}
+    ///
+    /// `result` is `None` if the part failed
+    fn after_part(&mut self, _day: usize, _part: usize, _result: Option<&PartResult>) {}
+
+    /// Called if parsing (`part` is `None`) or running a puzzle's part failed
+    ///
+    /// Is followed by the corresponding `after_parse` / `after_part` call
+    fn on_error(&mut self, _day: usize, _part: Option<usize>, _message: &str) {}
+
+    /// Called if running a puzzle's part took longer than `timeout`
+    ///
+    /// Is followed by the corresponding `after_part` call
+    fn on_timeout(&mut self, _day: usize, _part: usize, _timeout: Duration) {}
+
+    /// Called if parsing (`part` is `None`) or a puzzle's part was skipped, because its answer
+    /// was found in a [Cache][crate::cache::Cache]
+    ///
+    /// Is followed by the corresponding `after_parse` / `after_part` call
+    fn on_cache_hit(&mut self, _day: usize, _part: Option<usize>) {}
+
+    /// Whether this analyzer prints the parts' results itself, so the runner does not
+    fn prints_results(&self) -> bool {
+        false
+    }
+}
+
+/// The result of a puzzle's part, as passed to [Analyzer::after_part]
+#[derive(Clone, Copy)]
+pub struct PartResult<'a> {
+    value: &'a dyn Answer,
+}
+
+impl<'a> PartResult<'a> {
+    pub fn new(value: &'a dyn Answer) -> Self {
+        PartResult { value }
+    }
+
+    /// The value returned by [Day::part1][crate::Day::part1] / [Day::part2][crate::Day::part2]
+    pub fn value(&self) -> &dyn Debug {
+        self.value
+    }
+
+    /// The result's canonical string form, as stored in [Results][crate::Results], see [Answer]
+    pub fn answer(&self) -> String {
+        self.value.answer()
+    }
+}
+
+impl Debug for PartResult<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl Display for PartResult<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.answer())
+    }
+}
+
+/// Forwards every call of an [Analyzer] method to each analyzer in `$analyzers`
+macro_rules! fan_out {
+    ($self:ident => $analyzers:expr) => {
+        fn before_all(&mut $self) {
+            for analyzer in $analyzers {
+                analyzer.before_all();
+            }
+        }
+
+        fn after_all(&mut $self) {
+            for analyzer in $analyzers {
+                analyzer.after_all();
+            }
+        }
+
+        fn before_year(&mut $self, year: usize) {
+            for analyzer in $analyzers {
+                analyzer.before_year(year);
+            }
+        }
+
+        fn after_year(&mut $self, year: usize) {
+            for analyzer in $analyzers {
+                analyzer.after_year(year);
+            }
+        }
+
+        fn before_day(&mut $self, day: usize) {
+            for analyzer in $analyzers {
+                analyzer.before_day(day);
+            }
+        }
+
+        fn after_day(&mut $self, day: usize) {
+            for analyzer in $analyzers {
+                analyzer.after_day(day);
+            }
+        }
+
+        fn before_parse(&mut $self, day: usize) {
+            for analyzer in $analyzers {
+                analyzer.before_parse(day);
+            }
+        }
+
+        fn after_parse(&mut $self, day: usize) {
+            for analyzer in $analyzers {
+                analyzer.after_parse(day);
+            }
+        }
+
+        fn before_part(&mut $self, day: usize, part: usize) {
+            for analyzer in $analyzers {
+                analyzer.before_part(day, part);
+            }
+        }
+
+        fn after_part(&mut $self, day: usize, part: usize, result: Option<&PartResult>) {
+            for analyzer in $analyzers {
+                analyzer.after_part(day, part, result);
+            }
+        }
+
+        fn on_error(&mut $self, day: usize, part: Option<usize>, message: &str) {
+            for analyzer in $analyzers {
+                analyzer.on_error(day, part, message);
+            }
+        }
+
+        fn on_timeout(&mut $self, day: usize, part: usize, timeout: Duration) {
+            for analyzer in $analyzers {
+                analyzer.on_timeout(day, part, timeout);
+            }
+        }
+
+        fn on_cache_hit(&mut $self, day: usize, part: Option<usize>) {
+            for analyzer in $analyzers {
+                analyzer.on_cache_hit(day, part);
+            }
+        }
+    };
+}
+
+/// Runs several analyzers at once, in order
+macro_rules! impl_analyzer_for_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name: Analyzer),+> Analyzer for ($($name,)+) {
+            fan_out!(self => [$(&mut self.$idx as &mut dyn Analyzer),+]);
+
+            fn prints_results(&self) -> bool {
+                false $(|| self.$idx.prints_results())+
+            }
+        }
+    };
+}
+
+impl_analyzer_for_tuple!(A 0);
+impl_analyzer_for_tuple!(A 0, B 1);
+impl_analyzer_for_tuple!(A 0, B 1, C 2);
+impl_analyzer_for_tuple!(A 0, B 1, C 2, D 3);
+impl_analyzer_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_analyzer_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Runs all analyzers, in order
+impl<A: Analyzer> Analyzer for Vec<A> {
+    fan_out!(self => self.iter_mut());
+
+    fn prints_results(&self) -> bool {
+        self.iter().any(A::prints_results)
+    }
+}
+
+/// An analyzer that may be left out, e.g. depending on a command line option
+impl<A: Analyzer> Analyzer for Option<A> {
+    fan_out!(self => self.iter_mut());
+
+    fn prints_results(&self) -> bool {
+        self.as_ref().is_some_and(A::prints_results)
+    }
+}
+
+impl<A: Analyzer + ?Sized> Analyzer for Box<A> {
+    fan_out!(self => [&mut **self]);
+
+    fn prints_results(&self) -> bool {
+        (**self).prints_results()
+    }
+}
+
+/// File formats [TimeAnalyzer] can export its timings to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    /// A Markdown table; an existing file is only updated between [MARKDOWN_START_MARKER] and
+    /// [MARKDOWN_END_MARKER]
+    Markdown,
+}
+
+impl ExportFormat {
+    /// Guesses the format from a file's extension
+    pub fn from_path(path: &std::path::Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(ExportFormat::Json),
+            "csv" => Some(ExportFormat::Csv),
+            "md" => Some(ExportFormat::Markdown),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -32,80 +263,578 @@ pub struct TimeAnalyzer {
     start_all: Option<Instant>,
     time_all: Option<Duration>,
     start_day: Option<Instant>,
-    time_days: BTreeMap<usize, Duration>,
+    time_days: BTreeMap<usize, Vec<Duration>>,
     start_parse: Option<Instant>,
-    time_parse: BTreeMap<usize, Duration>,
+    time_parse: BTreeMap<usize, Vec<Duration>>,
     start_part: Option<Instant>,
-    time_part: BTreeMap<(usize,usize), Duration>,
+    time_part: BTreeMap<(usize, usize), Vec<Duration>>,
+    errors: BTreeSet<(usize, Option<usize>)>,
+    timeouts: BTreeSet<(usize, usize)>,
+    /// Steps whose answers came from a cache, so they were not timed
+    cached: BTreeSet<(usize, Option<usize>)>,
+    /// Runs a single day on a worker thread and must not report on its own
+    forked: bool,
+    /// Contains days that were run in parallel
+    merged: bool,
+    exports: Vec<(PathBuf, ExportFormat)>,
+    /// Earlier timings and the percentage a step may get slower than them
+    baseline: Option<(Baseline, f64)>,
+    year: Option<usize>,
+    /// Only exports its timings instead of printing them
+    quiet: bool,
+    /// Counts allocations for the table's allocation columns
+    allocations: Option<AllocAnalyzer>,
+}
+
+/// Statistics over all runs of a single step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    fn of(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let nanos = samples.iter().map(|d| d.as_nanos() as f64);
+        let mean = nanos.clone().sum::<f64>() / samples.len() as f64;
+        let variance = if samples.len() > 1 {
+            nanos.map(|n| (n - mean).powi(2)).sum::<f64>() / (samples.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+
+    /// Labels of the values returned by [Stats::values]
+    const LABELS: [&'static str; 4] = ["min", "med", "avg", "sd"];
+
+    fn values(self) -> [Duration; 4] {
+        [self.min, self.median, self.mean, self.stddev]
+    }
+}
+
+/// Formats a duration in its largest unit, e.g. `12ms`
+pub(crate) fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() > 0 {
+        format!("{}s", duration.as_secs())
+    } else if duration.as_millis() > 0 {
+        format!("{}ms", duration.as_millis())
+    } else if duration.as_micros() > 0 {
+        format!("{}μs", duration.as_micros())
+    } else {
+        format!("{}ns", duration.as_nanos())
+    }
+}
+
+/// The median of a step's runs, if it ran at all
+fn median(samples: Option<&Vec<Duration>>) -> Option<Duration> {
+    samples.map(|samples| Stats::of(samples).median)
 }
 
 /// A simple analyzer that measures and prints run times
+///
+/// If days are run repeatedly (see [run_day_repeated][crate::runner::run_day_repeated]), the
+/// table shows min / median / mean / standard deviation of every step, while totals and exports
+/// use the median.
 impl TimeAnalyzer {
     pub fn new() -> Self {
         TimeAnalyzer::default()
     }
 
+    /// Additionally writes all timings (in nanoseconds) to `path` when reporting
+    pub fn export_to(mut self, path: impl Into<PathBuf>, format: ExportFormat) -> Self {
+        self.exports.push((path.into(), format));
+        self
+    }
+
+    /// Does not print any tables, but still exports timings and detects regressions, e.g. to keep
+    /// the output machine readable
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+
+    /// Additionally counts the allocations of every parse and part and shows them in the table,
+    /// summed up per day, see [AllocAnalyzer]
+    ///
+    /// The binary must register a [CountingAllocator][crate::alloc::CountingAllocator], or all
+    /// counts are zero.
+    pub fn count_allocations(mut self) -> Self {
+        self.allocations = Some(AllocAnalyzer::new());
+        self
+    }
+
+    /// The allocations of parsing (`part` is `None`) or a part, if they were counted
+    pub fn allocations(&self, day: usize, part: Option<usize>) -> Option<AllocStats> {
+        self.allocations.as_ref()?.get(day, part)
+    }
+
+    /// Additionally compares all timings with `baseline` when reporting and flags steps that got
+    /// more than `threshold` percent slower, see [TimeAnalyzer::regressions]
+    pub fn compare_to(mut self, baseline: Baseline, threshold: f64) -> Self {
+        self.baseline = Some((baseline, threshold));
+        self
+    }
+
+    /// The parse and part steps that got slower than allowed compared to the baseline
+    pub fn regressions(&self) -> Vec<Regression> {
+        let Some((baseline, threshold)) = &self.baseline else {
+            return vec![];
+        };
+        self.rows()
+            .into_iter()
+            .flat_map(|(day, steps)| {
+                (0..3).filter_map(move |step| {
+                    let current = Duration::from_nanos(steps[step]? as u64);
+                    let before = baseline.get(self.year, day, step)?;
+                    Regression::check(day, step, before, current, *threshold)
+                })
+            })
+            .collect()
+    }
+
+    /// Whether any parse or part step failed or timed out
+    pub fn failed(&self) -> bool {
+        !self.errors.is_empty() || !self.timeouts.is_empty()
+    }
+
+    /// Table rows with the change of every step compared to the baseline in percent
+    ///
+    /// Regressions are marked with a `!`.
+    fn delta_lines(&self) -> Vec<String> {
+        let Some((baseline, _)) = &self.baseline else {
+            return vec![];
+        };
+        let regressions = self.regressions();
+        self.rows()
+            .into_iter()
+            .map(|(day, steps)| {
+                let cols = (0..4)
+                    .map(|step| {
+                        let cell = match (baseline.get(self.year, day, step), steps[step]) {
+                            (Some(before), Some(now)) => {
+                                let now = Duration::from_nanos(now as u64);
+                                let flagged =
+                                    regressions.iter().any(|r| r.day == day && r.step == step);
+                                format!(
+                                    "{:+.0}%{}",
+                                    baseline::delta(before, now),
+                                    if flagged { "!" } else { " " }
+                                )
+                            }
+                            _ => "- ".to_owned(),
+                        };
+                        format!(" {:>9}|", cell)
+                    })
+                    .collect::<String>();
+                format!("| {:>6} |{}", day, cols)
+            })
+            .collect()
+    }
+
     fn days(&self) -> Vec<usize> {
         self.time_days.keys().cloned().collect()
     }
 
+    /// Whether a step neither failed nor timed out, so its time counts towards the totals
+    fn finished(&self, day: usize, part: Option<usize>) -> bool {
+        !self.errors.contains(&(day, part))
+            && !part.is_some_and(|part| self.timeouts.contains(&(day, part)))
+    }
+
     fn total_parse(&self) -> Duration {
-        self.days().iter().map(|day| self.time_parse.get(day).unwrap()).cloned().reduce(|a,b| a.saturating_add(b)).unwrap_or_default()
+        self.days()
+            .iter()
+            .filter(|day| self.finished(**day, None))
+            .filter_map(|day| median(self.time_parse.get(day)))
+            .reduce(|a, b| a.saturating_add(b))
+            .unwrap_or_default()
     }
 
     fn total_part1(&self) -> Duration {
-        self.days().iter().filter_map(|day| self.time_part.get(&(*day, 1)))
-            .cloned().reduce(|a,b| a.saturating_add(b)).unwrap_or_default()
+        self.days()
+            .iter()
+            .filter(|day| self.finished(**day, Some(1)))
+            .filter_map(|day| median(self.time_part.get(&(*day, 1))))
+            .reduce(|a, b| a.saturating_add(b))
+            .unwrap_or_default()
     }
 
     fn total_part2(&self) -> Duration {
-        self.days().iter().filter_map(|day| self.time_part.get(&(*day, 2)))
-            .cloned().reduce(|a,b| a.saturating_add(b)).unwrap_or_default()
+        self.days()
+            .iter()
+            .filter(|day| self.finished(**day, Some(2)))
+            .filter_map(|day| median(self.time_part.get(&(*day, 2))))
+            .reduce(|a, b| a.saturating_add(b))
+            .unwrap_or_default()
     }
 
     fn total(&self) -> Duration {
-        self.time_all.unwrap_or(self.time_days.values().sum())
+        match self.time_all {
+            Some(time_all) if !self.repeated() => time_all,
+            _ => self.total_cpu(),
+        }
     }
 
-    fn report(&mut self) {
-        fn print_line(day: impl Display, parse: Duration, part1: Duration, part2: Duration, total: Duration) {
-            print!("| {:>6} |", day);
-            print_col(parse);
-            print_col(part1);
-            print_col(part2);
-            print_col(total);
-            print!("\n");
-        }
-
-        fn print_col(duration: Duration) {
-            if duration.as_secs() > 0 {
-                print!(" {:>7}s |", duration.as_secs());
-            } else if duration.as_millis() > 0 {
-                print!(" {:>6}ms |", duration.as_millis());
-            } else if duration.as_micros() > 0 {
-                print!(" {:>6}μs |", duration.as_micros());
+    /// The summed up time of all days, regardless whether they ran in parallel
+    fn total_cpu(&self) -> Duration {
+        self.time_days
+            .values()
+            .map(|samples| Stats::of(samples).median)
+            .sum()
+    }
+
+    /// Whether any day ran more than once
+    fn repeated(&self) -> bool {
+        self.time_days.values().any(|samples| samples.len() > 1)
+    }
+
+    /// Table rows with human readable timings, split into total rows and rows per day
+    ///
+    /// If days ran in parallel, an additional 'CPU' total row contains the summed up time of all
+    /// days, while the 'Total' row contains the wall-clock time.
+    fn lines(&self) -> (Vec<String>, Vec<String>) {
+        /// A cell's duration or why the step did not finish
+        type Cell = Result<Duration, &'static str>;
+
+        fn format_line(
+            day: impl Display,
+            parse: Cell,
+            part1: Cell,
+            part2: Cell,
+            total: Cell,
+            allocations: Option<AllocStats>,
+        ) -> String {
+            let mut line = format!(
+                "| {:>6} |{}{}{}{}",
+                day,
+                format_col(parse),
+                format_col(part1),
+                format_col(part2),
+                format_col(total)
+            );
+            if let Some(stats) = allocations {
+                line.push_str(&format!(
+                    " {:>8} | {:>8} | {:>8} |",
+                    stats.allocations,
+                    format_bytes(stats.bytes),
+                    format_bytes(stats.peak)
+                ));
+            }
+            line
+        }
+
+        fn format_col(duration: Cell) -> String {
+            match duration {
+                Ok(duration) => format!(" {:>8} |", format_duration(duration)),
+                Err(reason) => format!(" {:>8} |", reason),
+            }
+        }
+
+        let cell = |day: usize, part: Option<usize>, samples: Option<&Vec<Duration>>| {
+            if part.is_some_and(|part| self.timeouts.contains(&(day, part))) {
+                Err("TIMEOUT")
+            } else if self.errors.contains(&(day, part)) {
+                Err("FAILED")
+            } else if self.cached.contains(&(day, part)) {
+                Err("CACHED")
             } else {
-                print!(" {:>6}ns |", duration.as_nanos());
+                // Parts are not run if parsing failed or another part was selected
+                samples.map(|samples| Stats::of(samples)).ok_or("-")
             }
+        };
+
+        let allocations = self.allocations.as_ref();
+        let mut totals = vec![format_line(
+            "Total",
+            Ok(self.total_parse()),
+            Ok(self.total_part1()),
+            Ok(self.total_part2()),
+            Ok(self.total()),
+            allocations.map(AllocAnalyzer::total),
+        )];
+        if self.merged {
+            totals.push(format_line(
+                "CPU",
+                Ok(self.total_parse()),
+                Ok(self.total_part1()),
+                Ok(self.total_part2()),
+                Ok(self.total_cpu()),
+                allocations.map(AllocAnalyzer::total),
+            ));
         }
 
-        println!();
-        println!("+--------|----------|----------|----------|----------+");
-        println!("| Day    | Parse    | Part 1   | Part 2   | Total    |");
-        println!("+--------|----------|----------|----------|----------+");
-        print_line("Total", self.total_parse(), self.total_part1(), self.total_part2(), self.total());
-        println!("+----------------------------------------------------+");
+        let mut lines = vec![];
         for ref day in self.days() {
-            print_line(
-                day,
-                self.time_parse.get(day).cloned().unwrap_or_default(),
-                self.time_part.get(&(*day, 1)).cloned().unwrap_or_default(),
-                self.time_part.get(&(*day, 2)).cloned().unwrap_or_default(),
-                self.time_days.get(day).cloned().unwrap_or_default(),
+            let parse = cell(*day, None, self.time_parse.get(day));
+            let part1 = cell(*day, Some(1), self.time_part.get(&(*day, 1)));
+            let part2 = cell(*day, Some(2), self.time_part.get(&(*day, 2)));
+            let total = Stats::of(self.time_days.get(day).map_or(&[], Vec::as_slice));
+            let day_allocations = allocations.map(|allocations| allocations.day(*day));
+            if self.repeated() {
+                for (idx, label) in Stats::LABELS.iter().enumerate() {
+                    let stat = |stats: Stats| stats.values()[idx];
+                    lines.push(format_line(
+                        format!("{} {}", day, label),
+                        parse.map(stat),
+                        part1.map(stat),
+                        part2.map(stat),
+                        Ok(stat(total)),
+                        day_allocations,
+                    ));
+                }
+            } else {
+                lines.push(format_line(
+                    day,
+                    parse.map(|stats| stats.median),
+                    part1.map(|stats| stats.median),
+                    part2.map(|stats| stats.median),
+                    Ok(total.median),
+                    day_allocations,
+                ));
+            }
+        }
+        (totals, lines)
+    }
+
+    fn report(&mut self) {
+        if !self.quiet {
+            self.print();
+        }
+
+        for (path, format) in self.exports.iter() {
+            let result = match format {
+                ExportFormat::Json => std::fs::write(path, self.to_json()),
+                ExportFormat::Csv => std::fs::write(path, self.to_csv()),
+                ExportFormat::Markdown => write_markdown(path, &self.to_markdown()),
+            };
+            if let Err(err) = result {
+                eprintln!("Could not write timings to {}: {}", path.display(), err);
+            }
+        }
+    }
+
+    fn print(&self) {
+        let (totals, lines) = self.lines();
+        let (border, header, separator) = match self.allocations {
+            Some(_) => (
+                "+--------|----------|----------|----------|----------|----------|----------|----------+",
+                "| Day    | Parse    | Part 1   | Part 2   | Total    | Allocs   | Bytes    | Peak     |",
+                "+-------------------------------------------------------------------------------------+",
+            ),
+            None => (
+                "+--------|----------|----------|----------|----------+",
+                "| Day    | Parse    | Part 1   | Part 2   | Total    |",
+                "+----------------------------------------------------+",
+            ),
+        };
+
+        println!();
+        if let Some(year) = self.year {
+            println!("Year {}", year);
+        }
+        println!("{}", border);
+        println!("{}", header);
+        println!("{}", border);
+        for line in totals {
+            println!("{}", line);
+        }
+        println!("{}", separator);
+        for line in lines {
+            println!("{}", line);
+        }
+        println!("{}", border);
+        if let Some(runs) = self
+            .time_days
+            .values()
+            .map(Vec::len)
+            .max()
+            .filter(|&n| n > 1)
+        {
+            println!(
+                "{} runs per day: min / median / mean (avg) / standard deviation (sd)",
+                runs
             );
         }
-        println!("+--------|----------|----------|----------|----------+");
+
+        if let Some((_, threshold)) = &self.baseline {
+            println!();
+            println!("Compared to baseline (threshold: +{}%)", threshold);
+            println!("+--------|----------|----------|----------|----------+");
+            println!("| Day    | Parse    | Part 1   | Part 2   | Total    |");
+            println!("+--------|----------|----------|----------|----------+");
+            for line in self.delta_lines() {
+                println!("{}", line);
+            }
+            println!("+--------|----------|----------|----------|----------+");
+            let regressions = self.regressions();
+            if regressions.is_empty() {
+                println!("No regressions");
+            } else {
+                println!("{} regression(s):", regressions.len());
+                for regression in regressions {
+                    println!(" - {}", regression);
+                }
+            }
+        }
+    }
+
+    /// Renders the timings table in the layout of this repository's README
+    ///
+    /// With [count_allocations][Self::count_allocations], the table has the same allocation
+    /// columns as the printed one. If the days were run as a [Year][crate::Year], the table is
+    /// headed by the year.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = match self.year {
+            Some(year) => format!("## Year {}\n\n", year),
+            None => String::new(),
+        };
+        markdown.push_str("| Day    | Parse    | Part 1   | Part 2   | Total    |");
+        let mut alignment = String::from("| :----- | -------: | -------: | -------: | -------: |");
+        if self.allocations.is_some() {
+            markdown.push_str(" Allocs   | Bytes    | Peak     |");
+            alignment.push_str(" -------: | -------: | -------: |");
+        }
+        markdown.push('\n');
+        markdown.push_str(&alignment);
+        markdown.push('\n');
+        let (totals, lines) = self.lines();
+        for line in totals.into_iter().chain(lines) {
+            markdown.push_str(&line);
+            markdown.push('\n');
+        }
+        markdown
+    }
+
+    /// Median timings in nanoseconds of (parse, part 1, part 2, total) for every day
+    fn rows(&self) -> Vec<(usize, [Option<u128>; 4])> {
+        self.days()
+            .into_iter()
+            .map(|day| {
+                let nanos = |samples| median(samples).map(|median| median.as_nanos());
+                (
+                    day,
+                    [
+                        nanos(self.time_parse.get(&day)),
+                        nanos(self.time_part.get(&(day, 1))),
+                        nanos(self.time_part.get(&(day, 2))),
+                        nanos(self.time_days.get(&day)),
+                    ],
+                )
+            })
+            .collect()
     }
+
+    fn totals(&self) -> [u128; 4] {
+        [
+            self.total_parse().as_nanos(),
+            self.total_part1().as_nanos(),
+            self.total_part2().as_nanos(),
+            self.total().as_nanos(),
+        ]
+    }
+
+    /// Renders all timings in nanoseconds as JSON; parts that did not run are `null`
+    ///
+    /// If the days were run as a [Year][crate::Year], the JSON contains its `year`.
+    pub fn to_json(&self) -> String {
+        fn fields(values: [Option<u128>; 4]) -> String {
+            ["parse", "part1", "part2", "total"]
+                .iter()
+                .zip(values)
+                .map(|(name, value)| match value {
+                    Some(value) => format!("\"{}\": {}", name, value),
+                    None => format!("\"{}\": null", name),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+
+        let days = self
+            .rows()
+            .into_iter()
+            .map(|(day, values)| format!("    {{\"day\": {}, {}}}", day, fields(values)))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        let year = self
+            .year
+            .map(|year| format!("  \"year\": {},\n", year))
+            .unwrap_or_default();
+        format!(
+            "{{\n{}  \"unit\": \"ns\",\n  \"total\": {{{}}},\n  \"days\": [\n{}\n  ]\n}}\n",
+            year,
+            fields(self.totals().map(Some)),
+            days
+        )
+    }
+
+    /// Renders all timings in nanoseconds as CSV; parts that did not run are empty
+    ///
+    /// Every row starts with the [Year][crate::Year] the days were run as, if any.
+    pub fn to_csv(&self) -> String {
+        let year = self.year.map(|year| year.to_string()).unwrap_or_default();
+        let line = |day: &dyn Display, values: [Option<u128>; 4]| {
+            let values = values.map(|value| value.map(|v| v.to_string()).unwrap_or_default());
+            format!("{},{},{}\n", year, day, values.join(","))
+        };
+
+        let mut csv = String::from("year,day,parse_ns,part1_ns,part2_ns,total_ns\n");
+        csv.push_str(&line(&"total", self.totals().map(Some)));
+        for (day, values) in self.rows() {
+            csv.push_str(&line(&day, values));
+        }
+        csv
+    }
+}
+
+/// Writes `table` to a new file or between the timing markers of an existing one
+fn write_markdown(path: &Path, table: &str) -> std::io::Result<()> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => {
+            let content = replace_between_markers(&content, table).ok_or_else(|| {
+                std::io::Error::other(format!(
+                    "file exists but lacks '{}' and '{}' markers",
+                    MARKDOWN_START_MARKER, MARKDOWN_END_MARKER
+                ))
+            })?;
+            std::fs::write(path, content)
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => std::fs::write(path, table),
+        Err(err) => Err(err),
+    }
+}
+
+/// Replaces everything between the timing markers in `content` by `table`
+fn replace_between_markers(content: &str, table: &str) -> Option<String> {
+    let start = content.find(MARKDOWN_START_MARKER)? + MARKDOWN_START_MARKER.len();
+    let end = start + content[start..].find(MARKDOWN_END_MARKER)?;
+    Some(format!(
+        "{}\n\n{}\n{}",
+        &content[..start],
+        table,
+        &content[end..]
+    ))
 }
 
 impl Analyzer for TimeAnalyzer {
@@ -118,31 +847,381 @@ impl Analyzer for TimeAnalyzer {
         self.report();
     }
 
+    fn before_year(&mut self, year: usize) {
+        self.year = Some(year);
+    }
+
     fn before_day(&mut self, _day: usize) {
         self.start_day = Some(Instant::now());
     }
 
     fn after_day(&mut self, day: usize) {
-        self.time_days.insert(day, self.start_day.unwrap().elapsed());
+        let elapsed = self.start_day.unwrap().elapsed();
+        self.time_days.entry(day).or_default().push(elapsed);
 
-        if self.start_all.is_none() {
+        if self.start_all.is_none() && !self.forked {
             self.report();
         }
     }
 
-    fn before_parse(&mut self, _day: usize) {
+    fn before_parse(&mut self, day: usize) {
+        if let Some(allocations) = &mut self.allocations {
+            allocations.before_parse(day);
+        }
         self.start_parse = Some(Instant::now());
     }
 
     fn after_parse(&mut self, day: usize) {
-        self.time_parse.insert(day, self.start_parse.unwrap().elapsed());
+        let elapsed = self.start_parse.unwrap().elapsed();
+        if let Some(allocations) = &mut self.allocations {
+            allocations.after_parse(day);
+        }
+        if !self.cached.contains(&(day, None)) {
+            self.time_parse.entry(day).or_default().push(elapsed);
+        }
     }
 
-    fn before_part(&mut self, _day: usize, _part: usize) {
+    fn before_part(&mut self, day: usize, part: usize) {
+        if let Some(allocations) = &mut self.allocations {
+            allocations.before_part(day, part);
+        }
         self.start_part = Some(Instant::now());
     }
 
-    fn after_part(&mut self, day: usize, part: usize) {
-        self.time_part.insert((day, part), self.start_part.unwrap().elapsed());
+    fn after_part(&mut self, day: usize, part: usize, result: Option<&PartResult>) {
+        let elapsed = self.start_part.unwrap().elapsed();
+        if let Some(allocations) = &mut self.allocations {
+            allocations.after_part(day, part, result);
+        }
+        if !self.cached.contains(&(day, Some(part))) {
+            self.time_part.entry((day, part)).or_default().push(elapsed);
+        }
+    }
+
+    fn on_error(&mut self, day: usize, part: Option<usize>, _message: &str) {
+        self.errors.insert((day, part));
+    }
+
+    fn on_timeout(&mut self, day: usize, part: usize, _timeout: Duration) {
+        self.timeouts.insert((day, part));
+    }
+
+    fn on_cache_hit(&mut self, day: usize, part: Option<usize>) {
+        self.cached.insert((day, part));
+    }
+}
+
+impl ParallelAnalyzer for TimeAnalyzer {
+    fn fork(&self) -> Self {
+        TimeAnalyzer {
+            forked: true,
+            allocations: self.allocations.as_ref().map(AllocAnalyzer::fork),
+            ..Default::default()
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.time_days.extend(other.time_days);
+        self.time_parse.extend(other.time_parse);
+        self.time_part.extend(other.time_part);
+        self.errors.extend(other.errors);
+        self.timeouts.extend(other.timeouts);
+        self.cached.extend(other.cached);
+        if let (Some(allocations), Some(other)) = (&mut self.allocations, other.allocations) {
+            allocations.merge(other);
+        }
+        self.merged = true;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn analyzer() -> TimeAnalyzer {
+        let mut analyzer = TimeAnalyzer::new();
+        analyzer.time_all = Some(Duration::from_nanos(100));
+        analyzer.time_days.insert(1, vec![Duration::from_nanos(60)]);
+        analyzer
+            .time_parse
+            .insert(1, vec![Duration::from_nanos(10)]);
+        analyzer
+            .time_part
+            .insert((1, 1), vec![Duration::from_nanos(20)]);
+        analyzer
+            .time_part
+            .insert((1, 2), vec![Duration::from_nanos(30)]);
+        analyzer.time_days.insert(2, vec![Duration::from_nanos(40)]);
+        analyzer.time_parse.insert(2, vec![Duration::from_nanos(5)]);
+        analyzer
+            .time_part
+            .insert((2, 1), vec![Duration::from_nanos(35)]);
+        analyzer
+    }
+
+    #[test]
+    fn merge_parallel_days() {
+        let mut analyzer = analyzer();
+        let mut forked = analyzer.fork();
+        forked.time_days.insert(3, vec![Duration::from_nanos(50)]);
+        forked.time_parse.insert(3, vec![Duration::from_nanos(50)]);
+        analyzer.merge(forked);
+        assert_eq!(
+            analyzer.lines().0,
+            vec![
+                "|  Total |     65ns |     55ns |     30ns |    100ns |",
+                "|    CPU |     65ns |     55ns |     30ns |    150ns |",
+            ]
+        );
+        assert_eq!(
+            analyzer.lines().1[2],
+            "|      3 |     50ns |        - |        - |     50ns |"
+        );
+    }
+
+    #[test]
+    fn stats() {
+        let samples = [30, 10, 20, 40].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::of(&samples),
+            Stats {
+                min: Duration::from_nanos(10),
+                median: Duration::from_nanos(25),
+                mean: Duration::from_nanos(25),
+                stddev: Duration::from_nanos(13),
+            }
+        );
+    }
+
+    #[test]
+    fn repeated_runs() {
+        let mut analyzer = analyzer();
+        analyzer
+            .time_days
+            .get_mut(&2)
+            .unwrap()
+            .push(Duration::from_nanos(80));
+        analyzer
+            .time_parse
+            .get_mut(&2)
+            .unwrap()
+            .push(Duration::from_nanos(15));
+        let (totals, lines) = analyzer.lines();
+        assert_eq!(
+            totals[0],
+            "|  Total |     20ns |     55ns |     30ns |    120ns |"
+        );
+        assert_eq!(
+            &lines[4..],
+            [
+                "|  2 min |      5ns |     35ns |        - |     40ns |",
+                "|  2 med |     10ns |     35ns |        - |     60ns |",
+                "|  2 avg |     10ns |     35ns |        - |     60ns |",
+                "|   2 sd |      7ns |      0ns |        - |     28ns |",
+            ]
+        );
+    }
+
+    #[test]
+    fn mark_failed_cells() {
+        let mut analyzer = analyzer();
+        assert!(!analyzer.failed());
+        analyzer.on_error(2, Some(1), "Beacon not found");
+        assert!(analyzer.failed());
+        let (totals, lines) = analyzer.lines();
+        assert_eq!(
+            lines[1],
+            "|      2 |      5ns |   FAILED |        - |     40ns |"
+        );
+        assert_eq!(
+            totals[0],
+            "|  Total |     15ns |     20ns |     30ns |    100ns |"
+        );
+    }
+
+    #[test]
+    fn skip_parts_after_failed_parse() {
+        let mut analyzer = analyzer();
+        analyzer.time_part.remove(&(2, 1));
+        analyzer.on_error(2, None, "expected number");
+        let (totals, lines) = analyzer.lines();
+        assert_eq!(
+            lines[1],
+            "|      2 |   FAILED |        - |        - |     40ns |"
+        );
+        assert_eq!(
+            totals[0],
+            "|  Total |     10ns |     20ns |     30ns |    100ns |"
+        );
+    }
+
+    #[test]
+    fn mark_timed_out_cells() {
+        let mut analyzer = analyzer();
+        analyzer.on_timeout(1, 2, Duration::from_secs(10));
+        assert_eq!(
+            analyzer.lines().1[0],
+            "|      1 |     10ns |     20ns |  TIMEOUT |     60ns |"
+        );
+    }
+
+    #[test]
+    fn mark_cached_cells() {
+        let mut analyzer = analyzer();
+        analyzer.before_part(2, 2);
+        analyzer.on_cache_hit(2, Some(2));
+        analyzer.after_part(2, 2, Some(&PartResult::new(&"12")));
+        assert_eq!(
+            analyzer.lines().1[1],
+            "|      2 |      5ns |     35ns |   CACHED |     40ns |"
+        );
+        assert!(!analyzer.time_part.contains_key(&(2, 2)));
+    }
+
+    #[test]
+    fn allocation_columns() {
+        let mut analyzer = analyzer();
+        analyzer.allocations = Some(AllocAnalyzer::default());
+        assert_eq!(
+            analyzer.lines().1[0],
+            "|      1 |     10ns |     20ns |     30ns |     60ns |        0 |       0B |       0B |"
+        );
+        let markdown = analyzer.to_markdown();
+        let header = markdown.lines().take(2).collect::<Vec<_>>();
+        assert_eq!(
+            header,
+            vec![
+                "| Day    | Parse    | Part 1   | Part 2   | Total    | Allocs   | Bytes    | Peak     |",
+                "| :----- | -------: | -------: | -------: | -------: | -------: | -------: | -------: |",
+            ]
+        );
+        assert_eq!(analyzer.allocations(1, None), None);
+    }
+
+    #[derive(Default)]
+    struct CountAnalyzer(usize);
+
+    impl Analyzer for CountAnalyzer {
+        fn after_part(&mut self, _day: usize, _part: usize, _result: Option<&PartResult>) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn fan_out_to_all_analyzers() {
+        let mut analyzers = (
+            CountAnalyzer::default(),
+            vec![Box::<CountAnalyzer>::default() as Box<dyn Analyzer>],
+            TimeAnalyzer::new(),
+        );
+        analyzers.before_day(1);
+        analyzers.before_part(1, 1);
+        analyzers.after_part(1, 1, Some(&PartResult::new(&42)));
+        analyzers.on_error(1, Some(2), "Not found");
+
+        assert_eq!(analyzers.0 .0, 1);
+        assert_eq!(analyzers.2.time_part.len(), 1);
+        assert!(analyzers.2.errors.contains(&(1, Some(2))));
+    }
+
+    #[test]
+    fn compare_to_baseline() {
+        let mut baseline = Baseline::new();
+        let micros = |micros| Some(Duration::from_micros(micros));
+        baseline.insert(None, 1, [micros(10), micros(20), micros(30), None]);
+        let mut analyzer = TimeAnalyzer::new().compare_to(baseline, 10.0);
+        analyzer
+            .time_days
+            .insert(1, vec![Duration::from_micros(60)]);
+        analyzer
+            .time_parse
+            .insert(1, vec![Duration::from_micros(9)]);
+        analyzer
+            .time_part
+            .insert((1, 1), vec![Duration::from_micros(21)]);
+        analyzer
+            .time_part
+            .insert((1, 2), vec![Duration::from_micros(45)]);
+
+        assert_eq!(
+            analyzer.delta_lines(),
+            vec!["|      1 |     -10% |      +5% |     +50%!|        - |"]
+        );
+        assert_eq!(
+            analyzer.regressions(),
+            vec![Regression {
+                day: 1,
+                step: 2,
+                baseline: Duration::from_micros(30),
+                current: Duration::from_micros(45),
+            }]
+        );
+    }
+
+    #[test]
+    fn to_markdown() {
+        let mut with_year = analyzer();
+        with_year.before_year(2022);
+        assert!(with_year
+            .to_markdown()
+            .starts_with("## Year 2022\n\n| Day    | Parse    |"));
+        assert_eq!(
+            analyzer().to_markdown(),
+            "| Day    | Parse    | Part 1   | Part 2   | Total    |
+| :----- | -------: | -------: | -------: | -------: |
+|  Total |     15ns |     55ns |     30ns |    100ns |
+|      1 |     10ns |     20ns |     30ns |     60ns |
+|      2 |      5ns |     35ns |        - |     40ns |
+"
+        );
+    }
+
+    #[test]
+    fn replace_markdown_block() {
+        let (start, end) = (MARKDOWN_START_MARKER, MARKDOWN_END_MARKER);
+        let content = format!("# Title\n{}\nold\n{}\nrest\n", start, end);
+        assert_eq!(
+            replace_between_markers(&content, "new\n"),
+            Some(format!("# Title\n{}\n\nnew\n\n{}\nrest\n", start, end))
+        );
+        assert_eq!(replace_between_markers("# Title\n", "new\n"), None);
+    }
+
+    #[test]
+    fn to_csv() {
+        assert_eq!(
+            analyzer().to_csv(),
+            "year,day,parse_ns,part1_ns,part2_ns,total_ns
+,total,15,55,30,100
+,1,10,20,30,60
+,2,5,35,,40
+"
+        );
+        let mut with_year = analyzer();
+        with_year.before_year(2022);
+        assert!(with_year
+            .to_csv()
+            .starts_with("year,day,parse_ns,part1_ns,part2_ns,total_ns\n2022,total,15,"));
+    }
+
+    #[test]
+    fn to_json() {
+        let mut with_year = analyzer();
+        with_year.before_year(2022);
+        assert!(with_year
+            .to_json()
+            .starts_with("{\n  \"year\": 2022,\n  \"unit\""));
+        assert_eq!(
+            analyzer().to_json(),
+            r#"{
+  "unit": "ns",
+  "total": {"parse": 15, "part1": 55, "part2": 30, "total": 100},
+  "days": [
+    {"day": 1, "parse": 10, "part1": 20, "part2": 30, "total": 60},
+    {"day": 2, "parse": 5, "part1": 35, "part2": null, "total": 40}
+  ]
+}
+"#
+        );
     }
 }
@@ -0,0 +1,149 @@
+//! Expected answers to verify puzzle results against
+//!
+//! Answers are read from a small subset of TOML, with one table per day:
+//!
+//! ```toml
+//! # day 1
+//! [1]
+//! part1 = 24000
+//! part2 = "45000"
+//! ```
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{
+    toml::{self, Entry},
+    ParseError,
+};
+
+/// The formatted results of a run, keyed by (day, part)
+pub type Results = BTreeMap<(usize, usize), String>;
+
+/// A set of expected answers, keyed by (day, part)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(usize, usize), String>);
+
+/// The outcome of comparing a part's result with its expected answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    Missing,
+}
+
+impl Answers {
+    pub fn new() -> Self {
+        Answers::default()
+    }
+
+    /// Parses answers from a TOML file's content
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::new();
+        for Entry { day, key, value } in toml::parse(input, "'part<n> = <answer>'")? {
+            let part = key
+                .strip_prefix("part")
+                .and_then(|part| part.parse::<usize>().ok())
+                .ok_or_else(|| ParseError::at(input, key, "'part1' or 'part2'"))?;
+            answers.insert(day, part, value);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn insert(&mut self, day: usize, part: usize, answer: impl Into<String>) {
+        self.0.insert((day, part), answer.into());
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compares a part's formatted result (if there is any) with the expected answer
+    ///
+    /// Surrounding whitespace and the quotes of `Debug`-formatted strings are ignored.
+    pub fn verify(&self, day: usize, part: usize, actual: Option<&str>) -> Verdict {
+        match (self.get(day, part), actual) {
+            (None, _) => Verdict::Missing,
+            (Some(expected), Some(actual)) if normalize(actual) == expected.trim() => Verdict::Pass,
+            (Some(expected), actual) => Verdict::Fail {
+                expected: expected.to_owned(),
+                actual: actual.map(str::to_owned),
+            },
+        }
+    }
+}
+
+fn normalize(answer: &str) -> &str {
+    let answer = answer.trim();
+    answer
+        .strip_prefix('"')
+        .and_then(|answer| answer.strip_suffix('"'))
+        .unwrap_or(answer)
+        .trim()
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "FAIL (expected {}, got {})", expected, normalize(actual)),
+            Verdict::Fail {
+                expected,
+                actual: None,
+            } => write!(f, "FAIL (expected {}, got no result)", expected),
+            Verdict::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ANSWERS: &str = "# expected answers
+[1]
+part1 = 24000
+part2 = \"45000\" # as string
+
+[5]
+part1 = \"CMZ\"
+";
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 1), Some("24000"));
+        assert_eq!(answers.get(1, 2), Some("45000"));
+        assert_eq!(answers.get(5, 1), Some("CMZ"));
+        assert_eq!(answers.get(5, 2), None);
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(
+            Answers::parse("[1]\npart1 = CMZ"),
+            Err(ParseError::new(2, 9, "integer or quoted string"))
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.verify(1, 1, Some("24000")), Verdict::Pass);
+        assert_eq!(answers.verify(5, 1, Some("\"CMZ\"")), Verdict::Pass);
+        assert_eq!(answers.verify(5, 2, Some("\"MCD\"")), Verdict::Missing);
+        assert_eq!(
+            answers.verify(1, 2, None),
+            Verdict::Fail {
+                expected: "45000".to_owned(),
+                actual: None
+            }
+        );
+    }
+}
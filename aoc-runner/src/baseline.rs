@@ -0,0 +1,196 @@
+//! Timings of an earlier run to detect performance regressions against
+//!
+//! A baseline is the CSV export of a [TimeAnalyzer][crate::TimeAnalyzer]:
+//!
+//! ```csv
+//! year,day,parse_ns,part1_ns,part2_ns,total_ns
+//! 2022,total,15,55,30,100
+//! 2022,1,10,20,30,60
+//! 2022,2,5,35,,40
+//! ```
+//!
+//! Timings are matched by year and day, so a baseline of one year is never compared with
+//! another year's days. The year is empty for days that were not run as a [Year][crate::Year].
+
+use std::{collections::BTreeMap, fmt::Display, time::Duration};
+
+use crate::{analyzer::format_duration, ParseError};
+
+/// Names of a day's steps, in the order of a baseline's columns
+pub const STEPS: [&str; 4] = ["Parse", "Part 1", "Part 2", "Total"];
+
+/// Differences below this are never regressions, as they are usually just noise
+pub const NOISE_FLOOR: Duration = Duration::from_micros(1);
+
+/// Timings of (parse, part 1, part 2, total) for every year and day; steps that did not run are
+/// `None`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline(BTreeMap<(Option<usize>, usize), [Option<Duration>; 4]>);
+
+impl Baseline {
+    pub fn new() -> Self {
+        Baseline::default()
+    }
+
+    /// Parses a baseline from a CSV file's content, ignoring its `total` row
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut baseline = Baseline::new();
+        for line in input.lines().skip(1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut columns = line.split(',').map(str::trim);
+            let year = columns.next().unwrap_or_default();
+            let year = match year {
+                "" => None,
+                year => Some(
+                    year.parse()
+                        .map_err(|_| ParseError::at(input, year, "year"))?,
+                ),
+            };
+            let day = columns
+                .next()
+                .ok_or_else(|| ParseError::at(input, line, "6 columns"))?;
+            if day == "total" {
+                continue;
+            }
+            let day = day
+                .parse()
+                .map_err(|_| ParseError::at(input, day, "day number"))?;
+
+            let mut steps = [None; 4];
+            for step in steps.iter_mut() {
+                let nanos = columns
+                    .next()
+                    .ok_or_else(|| ParseError::at(input, line, "6 columns"))?;
+                if !nanos.is_empty() {
+                    let nanos = nanos
+                        .parse()
+                        .map_err(|_| ParseError::at(input, nanos, "nanoseconds"))?;
+                    *step = Some(Duration::from_nanos(nanos));
+                }
+            }
+            baseline.insert(year, day, steps);
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn insert(&mut self, year: Option<usize>, day: usize, steps: [Option<Duration>; 4]) {
+        self.0.insert((year, day), steps);
+    }
+
+    /// The timing of a step (an index into [STEPS]) of a year's day
+    pub fn get(&self, year: Option<usize>, day: usize, step: usize) -> Option<Duration> {
+        self.0.get(&(year, day))?[step]
+    }
+}
+
+/// The relative change from `baseline` to `current` in percent
+pub fn delta(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_nanos().max(1) as f64;
+    (current.as_nanos() as f64 - baseline) / baseline * 100.0
+}
+
+/// A step that got slower than allowed compared to the baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub day: usize,
+    /// An index into [STEPS]
+    pub step: usize,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// Compares a step's timing with the baseline and returns a regression if it got more than
+    /// `threshold` percent (and [NOISE_FLOOR]) slower
+    pub fn check(
+        day: usize,
+        step: usize,
+        baseline: Duration,
+        current: Duration,
+        threshold: f64,
+    ) -> Option<Self> {
+        (delta(baseline, current) > threshold && current > baseline + NOISE_FLOOR).then_some(
+            Regression {
+                day,
+                step,
+                baseline,
+                current,
+            },
+        )
+    }
+
+    /// The relative change in percent
+    pub fn delta(&self) -> f64 {
+        delta(self.baseline, self.current)
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} {}: {} -> {} ({:+.0}%)",
+            self.day,
+            STEPS[self.step],
+            format_duration(self.baseline),
+            format_duration(self.current),
+            self.delta()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BASELINE: &str = "year,day,parse_ns,part1_ns,part2_ns,total_ns
+2022,total,15,55,30,100
+2022,1,10,20,30,60
+2022,2,5,35,,40
+,1,1,2,3,6
+";
+
+    #[test]
+    fn parse() {
+        let baseline = Baseline::parse(BASELINE).unwrap();
+        assert_eq!(
+            baseline.get(Some(2022), 1, 2),
+            Some(Duration::from_nanos(30))
+        );
+        assert_eq!(baseline.get(Some(2022), 2, 2), None);
+        assert_eq!(baseline.get(Some(2022), 3, 0), None);
+        assert_eq!(baseline.get(Some(2021), 1, 2), None);
+        assert_eq!(baseline.get(None, 1, 2), Some(Duration::from_nanos(3)));
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(
+            Baseline::parse("year,day,parse_ns,part1_ns,part2_ns,total_ns\n2022,1,10,x,30,60")
+                .map_err(|e| e.to_string()),
+            Err("line 2, column 11: expected nanoseconds".to_owned())
+        );
+        // Baselines from before timings were keyed by year lack a column
+        assert_eq!(
+            Baseline::parse("day,parse_ns,part1_ns,part2_ns,total_ns\n1,10,20,30,60")
+                .map_err(|e| e.to_string()),
+            Err("line 2, column 1: expected 6 columns".to_owned())
+        );
+    }
+
+    #[test]
+    fn regressions() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Regression::check(5, 2, ms(10), ms(12), 10.0).map(|r| r.to_string()),
+            Some("Day 5 Part 2: 10ms -> 12ms (+20%)".to_owned())
+        );
+        assert_eq!(Regression::check(5, 2, ms(10), ms(11), 10.0), None);
+        let ns = Duration::from_nanos;
+        assert_eq!(Regression::check(5, 2, ns(10), ns(50), 10.0), None);
+    }
+}
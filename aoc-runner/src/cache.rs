@@ -0,0 +1,183 @@
+//! Answers of earlier runs, so parts whose code and input did not change are not solved again
+//!
+//! Answers are keyed by day, part, a hash of the [normalized][crate::input] input and the day's
+//! [parameters][crate::Day::params], and the id of the build that solved them. A cache is stored
+//! as CSV, with newlines and backslashes in answers escaped:
+//!
+//! ```csv
+//! day,part,input_hash,build_id,answer
+//! 1,1,9a1c0e5d2b7f3e41,00000000002a91c4,24000
+//! 10,2,5e3f8c1a9b0d7e22,00000000002a91c4,##..\n#..#
+//! ```
+
+use std::{collections::BTreeMap, path::PathBuf, sync::Mutex, time::UNIX_EPOCH};
+
+const HEADER: &str = "day,part,input_hash,build_id,answer";
+
+/// (day, part, input hash)
+type Key = (usize, usize, u64);
+
+/// Cached answers of a single build, keyed by (day, part, input hash)
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+    build_id: u64,
+    /// The answers and whether any were added since loading
+    answers: Mutex<(BTreeMap<Key, String>, bool)>,
+}
+
+impl Cache {
+    /// Loads the answers of build `build_id` from `path`
+    ///
+    /// A missing or unreadable cache file starts an empty cache, as does any line that cannot be
+    /// parsed. Answers of other builds are dropped.
+    pub fn load(path: impl Into<PathBuf>, build_id: u64) -> Self {
+        let path = path.into();
+        let mut answers = BTreeMap::new();
+        for line in std::fs::read_to_string(&path).unwrap_or_default().lines() {
+            let mut fields = line.splitn(5, ',');
+            let mut field = || fields.next();
+            let (Some(day), Some(part), Some(input_hash), Some(build), Some(answer)) =
+                (field(), field(), field(), field(), field())
+            else {
+                continue;
+            };
+            let (Ok(day), Ok(part), Ok(input_hash), Ok(build)) = (
+                day.parse(),
+                part.parse(),
+                u64::from_str_radix(input_hash, 16),
+                u64::from_str_radix(build, 16),
+            ) else {
+                continue;
+            };
+            if build == build_id {
+                answers.insert((day, part, input_hash), unescape(answer));
+            }
+        }
+
+        Cache {
+            path,
+            build_id,
+            answers: Mutex::new((answers, false)),
+        }
+    }
+
+    /// The cached answer of a part, if it was solved for the same input by this build
+    pub fn get(&self, day: usize, part: usize, input_hash: u64) -> Option<String> {
+        let answers = self.answers.lock().unwrap();
+        answers.0.get(&(day, part, input_hash)).cloned()
+    }
+
+    pub fn insert(&self, day: usize, part: usize, input_hash: u64, answer: impl Into<String>) {
+        let mut answers = self.answers.lock().unwrap();
+        answers.0.insert((day, part, input_hash), answer.into());
+        answers.1 = true;
+    }
+
+    /// Writes the answers back to the cache file, if any were added
+    pub fn save(&self) -> std::io::Result<()> {
+        let answers = self.answers.lock().unwrap();
+        if !answers.1 {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut csv = format!("{}\n", HEADER);
+        for ((day, part, input_hash), answer) in answers.0.iter() {
+            csv.push_str(&format!(
+                "{},{},{:016x},{:016x},{}\n",
+                day,
+                part,
+                input_hash,
+                self.build_id,
+                escape(answer)
+            ));
+        }
+        std::fs::write(&self.path, csv)
+    }
+}
+
+/// Identifies the running binary by its size and modification time, which change with every
+/// build
+pub fn build_id() -> std::io::Result<u64> {
+    let metadata = std::env::current_exe()?.metadata()?;
+    let modified = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let mut bytes = metadata.len().to_le_bytes().to_vec();
+    bytes.extend(modified.as_nanos().to_le_bytes());
+    Ok(fnv1a(&bytes))
+}
+
+/// Hashes an input together with the day's parameter values, which may change its answers
+pub fn input_hash(input: &str, params: &[(&str, String)]) -> u64 {
+    let mut bytes = input.as_bytes().to_vec();
+    for (key, value) in params {
+        bytes.push(0);
+        bytes.extend(key.as_bytes());
+        bytes.push(b'=');
+        bytes.extend(value.as_bytes());
+    }
+    fnv1a(&bytes)
+}
+
+/// The 64 bit FNV-1a hash, which unlike [std::hash::DefaultHasher] is stable across builds
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Puts an answer on a single line, escaping newlines as `\n` and backslashes as `\\`
+pub(crate) fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-{}.csv", std::process::id()));
+        let cache = Cache::load(&path, 1);
+        cache.insert(10, 2, 7, "#.\\\n.#");
+        cache.insert(1, 1, 7, "24000");
+        cache.save().unwrap();
+
+        let cache = Cache::load(&path, 1);
+        assert_eq!(cache.get(10, 2, 7).as_deref(), Some("#.\\\n.#"));
+        assert_eq!(cache.get(1, 1, 7).as_deref(), Some("24000"));
+        assert_eq!(cache.get(1, 1, 8), None);
+        assert_eq!(Cache::load(&path, 2).get(1, 1, 7), None);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn hash_params() {
+        let params = [("row", "10".to_owned())];
+        assert_eq!(input_hash("1", &params), input_hash("1", &params));
+        assert_ne!(input_hash("1", &params), input_hash("1", &[]));
+        assert_ne!(input_hash("1", &[]), input_hash("2", &[]));
+    }
+}
@@ -1,9 +1,24 @@
 //! Defines a common interface for [Advent of Code](http://adventofcode.com) puzzles
 
+use std::fmt::{self, Debug, Write};
+
+use crate::{Params, ParseError, PartResult};
+
 /// A day's challenge
-pub trait Day: Default {
-    type Result1: std::fmt::Debug + Sized;
-    type Result2: std::fmt::Debug + Sized;
+///
+/// Days and their results must be [Send], so they can be run on worker threads
+pub trait Day: Default + Send + 'static {
+    type Result1: Answer;
+    type Result2: Answer;
+
+    /// Optional: the puzzle's example input and its expected answers
+    const EXAMPLE: Option<Example> = None;
+
+    /// Optional: whether trailing whitespace is trimmed from the input before parsing
+    ///
+    /// Days whose input is padded with spaces opt out; a byte order mark and CRLF line endings
+    /// are normalized either way, see [input][crate::input].
+    const TRIM_INPUT: bool = true;
 
     /// Part 1 of this day's challenge
     fn part1(&mut self) -> Self::Result1;
@@ -11,16 +26,160 @@ pub trait Day: Default {
     /// Part 2 of this day's challenge
     fn part2(&mut self) -> Self::Result2;
 
-    /// Print result of part 1
-    fn print_part1(&self, result: Self::Result1) {
-        println!(" - Part 1: {:?}", result)
+    /// Print result of part 1 to `out`, which the runner prints or buffers
+    fn print_part1(&self, result: Self::Result1, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, " - Part 1: {:?}", result)
     }
 
-    /// Print result of part 2
-    fn print_part2(&self, result: Self::Result2) {
-        println!(" - Part 2: {:?}", result)
+    /// Print result of part 2 to `out`, which the runner prints or buffers
+    fn print_part2(&self, result: Self::Result2, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, " - Part 2: {:?}", result)
     }
 
     /// Optional: parse input to use later in part1/part2
-    fn parse(&mut self, _input: &str) {}
+    ///
+    /// Returns a [ParseError] if the input is malformed, so the runner can skip this day
+    fn parse(&mut self, _input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    /// Optional: the day's runtime parameters, which the runner can set before parsing
+    ///
+    /// Their [Default] values should be the ones of the real puzzle, see [Params].
+    fn params(&mut self) -> Option<&mut dyn Params> {
+        None
+    }
+}
+
+/// A part's result
+///
+/// Results are printed with [Debug] by [Day::print_part1] / [Day::print_part2], which may be a
+/// pretty rendering, e.g. of an image. Their answer is the canonical string that is compared
+/// with expected answers and printed by machine readable output formats.
+pub trait Answer: Debug + Send + 'static {
+    /// The answer in the form it is compared with expected answers
+    ///
+    /// Usually this is the answer as it would be submitted on the puzzle's page, e.g. `24000` or
+    /// `CMZ`. Results that are only read off an image, like letters drawn with pixels, are the
+    /// image as plain text instead, with a line per row.
+    fn answer(&self) -> String;
+}
+
+/// Answers of results that are their [Display][std::fmt::Display] form
+macro_rules! impl_answer_for_display {
+    ($($ty:ty),+) => {
+        $(
+            impl Answer for $ty {
+                fn answer(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )+
+    };
+}
+
+impl_answer_for_display!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    char,
+    bool,
+    String,
+    &'static str
+);
+
+/// A puzzle's example input with the answers given in the puzzle's description
+///
+/// Answers are compared like [Answers][crate::Answers], so they are written as `"24000"` or
+/// `"CMZ"`. An answer is `None` if the example does not apply to that part.
+///
+/// `params` are set on the day before it runs on the example, e.g. if the example checks a
+/// smaller area than the puzzle, see [Day::params].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+/// A [Day] with its types erased, e.g. to handle all days of a `#[derive(AoC)]` struct in a loop
+pub trait DynDay: Send {
+    /// Parses the input, see [Day::parse]
+    fn parse(&mut self, input: &str) -> Result<(), ParseError>;
+
+    /// Solves a part and discards its result
+    ///
+    /// The result is passed through [std::hint::black_box], so this can be benchmarked.
+    fn solve(&mut self, part: usize);
+
+    /// Solves a part and returns its formatted answer, see [PartResult::answer]
+    fn answer(&mut self, part: usize) -> String;
+
+    /// The day's runtime parameters, see [Day::params]
+    fn params(&mut self) -> Option<&mut dyn Params>;
+}
+
+impl<D: Day> DynDay for D {
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        Day::parse(self, input)
+    }
+
+    fn solve(&mut self, part: usize) {
+        match part {
+            1 => drop(std::hint::black_box(self.part1())),
+            2 => drop(std::hint::black_box(self.part2())),
+            _ => panic!("Invalid part: {}. Valid parts are: 1,2", part),
+        }
+    }
+
+    fn answer(&mut self, part: usize) -> String {
+        match part {
+            1 => PartResult::new(&self.part1()).answer(),
+            2 => PartResult::new(&self.part2()).answer(),
+            _ => panic!("Invalid part: {}. Valid parts are: 1,2", part),
+        }
+    }
+
+    fn params(&mut self) -> Option<&mut dyn Params> {
+        Day::params(self)
+    }
+}
+
+/// A day of a `#[derive(AoC)]` struct, as listed by its generated `days()`
+#[derive(Debug, Clone, Copy)]
+pub struct DayInfo {
+    /// The day's number, starting at 1
+    pub day: usize,
+    /// The name of the day's type, e.g. `Day01`
+    pub name: &'static str,
+    /// Creates a [Default] instance of the day
+    pub new: fn() -> Box<dyn DynDay>,
+    /// The day's example, see [Day::EXAMPLE]
+    pub example: Option<Example>,
+    /// Whether the day's input is trimmed, see [Day::TRIM_INPUT]
+    pub trim_input: bool,
+}
+
+impl DayInfo {
+    /// Describes `D` as the given day
+    pub fn of<D: Day>(day: usize) -> Self {
+        let name = std::any::type_name::<D>();
+        Self {
+            day,
+            name: name.rsplit("::").next().unwrap_or(name),
+            new: || Box::<D>::default(),
+            example: D::EXAMPLE,
+            trim_input: D::TRIM_INPUT,
+        }
+    }
 }
@@ -0,0 +1,124 @@
+//! Errors reported while running a day's puzzle
+
+use std::fmt::Display;
+
+use crate::ParamError;
+
+/// An error that occured while parsing a puzzle's input
+///
+/// Lines and columns are 1-based and columns are counted in chars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error pointing at the start of `token`
+    ///
+    /// `token` is expected to be a slice of `input`, e.g. obtained by `lines()` or `split()`.
+    /// If it is not, the error points at the end of `input`.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(input.len());
+        Self::at_offset(input, offset, expected)
+    }
+
+    /// Creates an error pointing at the byte `offset` inside `input`
+    pub fn at_offset(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+        Self::new(line, column, expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Why a part could not be solved by [solve][crate::runner::solve]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// There is no such day
+    InvalidDay(usize),
+    /// Parts are 1 or 2
+    InvalidPart(usize),
+    /// A parameter could not be set on the day
+    Param(ParamError),
+    Parse(ParseError),
+    /// Parsing or solving the part panicked with this message
+    Panicked(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::InvalidDay(day) => write!(f, "day {} does not exist", day),
+            SolveError::InvalidPart(part) => write!(f, "invalid part {}: expected 1 or 2", part),
+            SolveError::Param(err) => write!(f, "{}", err),
+            SolveError::Parse(err) => write!(f, "could not parse the input: {}", err),
+            SolveError::Panicked(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SolveError::Param(err) => Some(err),
+            SolveError::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn at_token() {
+        let input = "abc\ndef ghi\n";
+        let token = input.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+        assert_eq!(
+            ParseError::at(input, token, "x"),
+            ParseError::new(2, 5, "x")
+        );
+    }
+
+    #[test]
+    fn at_foreign_token() {
+        let input = "abc\ndef";
+        let token = String::from("def");
+        assert_eq!(
+            ParseError::at(input, &token, "x"),
+            ParseError::new(2, 4, "x")
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Loading and normalizing puzzle inputs, so a day sees the same input whether it was read from
+//! a file, stdin or given as a string
+//!
+//! Inputs are read verbatim and normalized by the runner right before a day parses them:
+//! a leading byte order mark is removed, CRLF line endings become LF and, unless the day opts out
+//! with [Day::TRIM_INPUT][crate::Day::TRIM_INPUT], trailing whitespace is removed from every line
+//! and from the end of the input.
+
+use std::{borrow::Cow, io::Read, path::Path};
+
+/// The UTF-8 byte order mark, as some editors put it at the start of a file
+const BOM: char = '\u{feff}';
+
+/// Reads an input file verbatim
+pub fn read(path: impl AsRef<Path>) -> std::io::Result<String> {
+    std::fs::read_to_string(path)
+}
+
+/// Reads an input from stdin verbatim
+pub fn read_stdin() -> std::io::Result<String> {
+    let mut input = String::new();
+    std::io::stdin().lock().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// Normalizes an input, see the [module docs][self]
+///
+/// With `trim`, trailing whitespace is removed from every line and from the end of the input, so
+/// there is no final newline. Without it, only the byte order mark and CRLF line endings are
+/// normalized, e.g. for inputs whose lines are padded with spaces.
+pub fn normalize(input: &str, trim: bool) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    if trim {
+        let untrimmed = input.ends_with(char::is_whitespace)
+            || input
+                .lines()
+                .any(|line| line.ends_with(char::is_whitespace));
+        if !untrimmed && !input.contains('\r') {
+            return Cow::Borrowed(input);
+        }
+        let mut normalized = input
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n");
+        normalized.truncate(normalized.trim_end().len());
+        Cow::Owned(normalized)
+    } else if input.contains("\r\n") {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn trim() {
+        assert_eq!(normalize("1\n2\n\n3\n", true), "1\n2\n\n3");
+        assert_eq!(normalize("\u{feff}1 \r\n2\t\r\n\r\n", true), "1\n2");
+        assert_eq!(normalize("  [D]  \n1", true), "  [D]\n1");
+        assert!(matches!(normalize("1\n2", true), Cow::Borrowed("1\n2")));
+    }
+
+    #[test]
+    fn keep_whitespace() {
+        assert_eq!(normalize("\u{feff}  [D]  \r\n1\r\n", false), "  [D]  \n1\n");
+        assert!(matches!(
+            normalize("  a \n", false),
+            Cow::Borrowed("  a \n")
+        ));
+    }
+}
@@ -1,7 +1,34 @@
 /// Scaffold methods for an AoC runner
-
+pub mod alloc;
 pub mod analyzer;
+pub mod answers;
+pub mod baseline;
+pub mod cache;
 pub mod day;
+pub mod error;
+pub mod input;
+pub mod log;
+pub mod output;
+pub mod parallel;
+pub mod params;
+pub mod runner;
+pub mod testing;
+mod toml;
+pub mod trace;
+pub mod year;
 
-pub use analyzer::{Analyzer, TimeAnalyzer};
-pub use day::Day;
+pub use analyzer::{
+    Analyzer, ExportFormat, PartResult, TimeAnalyzer, MARKDOWN_END_MARKER, MARKDOWN_START_MARKER,
+};
+pub use answers::{Answers, Results, Verdict};
+pub use baseline::{Baseline, Regression};
+pub use cache::Cache;
+pub use day::{Answer, Day, DayInfo, DynDay, Example};
+pub use error::{ParseError, SolveError};
+pub use log::LogAnalyzer;
+pub use output::{Format, OutputAnalyzer};
+pub use parallel::ParallelAnalyzer;
+pub use params::{ParamError, ParamValues, Params};
+pub use runner::{run_day, solve};
+pub use trace::TraceAnalyzer;
+pub use year::Year;
@@ -0,0 +1,110 @@
+//! An analyzer that logs every event of a run
+
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{analyzer::PartResult, Analyzer, ParallelAnalyzer};
+
+/// Logs every event to stderr, together with the time elapsed since the first event
+#[derive(Default, Clone)]
+pub struct LogAnalyzer {
+    start: Option<Instant>,
+}
+
+impl LogAnalyzer {
+    pub fn new() -> Self {
+        LogAnalyzer::default()
+    }
+
+    fn log(&mut self, event: impl Display) {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        eprintln!("[{:>12.3?}] {}", start.elapsed(), event);
+    }
+}
+
+impl Analyzer for LogAnalyzer {
+    fn before_all(&mut self) {
+        self.log("Running all days");
+    }
+
+    fn after_all(&mut self) {
+        self.log("Finished all days");
+    }
+
+    fn before_year(&mut self, year: usize) {
+        self.log(format_args!("Year {}: started", year));
+    }
+
+    fn after_year(&mut self, year: usize) {
+        self.log(format_args!("Year {}: finished", year));
+    }
+
+    fn before_day(&mut self, day: usize) {
+        self.log(format_args!("Day {}: started", day));
+    }
+
+    fn after_day(&mut self, day: usize) {
+        self.log(format_args!("Day {}: finished", day));
+    }
+
+    fn before_parse(&mut self, day: usize) {
+        self.log(format_args!("Day {}: parsing input", day));
+    }
+
+    fn after_parse(&mut self, day: usize) {
+        self.log(format_args!("Day {}: parsed input", day));
+    }
+
+    fn before_part(&mut self, day: usize, part: usize) {
+        self.log(format_args!("Day {} Part {}: started", day, part));
+    }
+
+    fn after_part(&mut self, day: usize, part: usize, result: Option<&PartResult>) {
+        if let Some(result) = result {
+            self.log(format_args!(
+                "Day {} Part {}: finished with {}",
+                day, part, result
+            ));
+        } else {
+            self.log(format_args!("Day {} Part {}: finished", day, part));
+        }
+    }
+
+    fn on_error(&mut self, day: usize, part: Option<usize>, message: &str) {
+        match part {
+            Some(part) => self.log(format_args!(
+                "Day {} Part {}: failed: {}",
+                day, part, message
+            )),
+            None => self.log(format_args!("Day {}: parsing failed: {}", day, message)),
+        }
+    }
+
+    fn on_timeout(&mut self, day: usize, part: usize, timeout: Duration) {
+        self.log(format_args!(
+            "Day {} Part {}: timed out after {:?}",
+            day, part, timeout
+        ));
+    }
+
+    fn on_cache_hit(&mut self, day: usize, part: Option<usize>) {
+        match part {
+            Some(part) => self.log(format_args!("Day {} Part {}: answer cached", day, part)),
+            None => self.log(format_args!(
+                "Day {}: parsing skipped, all answers cached",
+                day
+            )),
+        }
+    }
+}
+
+/// Forked analyzers log immediately, relative to the same start
+impl ParallelAnalyzer for LogAnalyzer {
+    fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    fn merge(&mut self, _other: Self) {}
+}
@@ -0,0 +1,257 @@
+//! Machine readable output of the parts' answers, replacing the runner's human readable output
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use crate::{cache::escape, Analyzer, ParallelAnalyzer, PartResult};
+
+/// How the answers of a run are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// The runner's output: `Day N` followed by every part's [Debug] result
+    #[default]
+    Human,
+    /// Only the answers, one per line, with newlines in answers escaped as `\n` and backslashes
+    /// as `\\`
+    Plain,
+    /// One JSON object per part and line, e.g.
+    /// `{"day":1,"part":2,"answer":"45000","duration_ns":1200}`; cached parts have
+    /// `"cached":true` instead of a duration
+    Json,
+}
+
+impl Format {
+    /// The format of a name, e.g. `json`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "human" => Some(Format::Human),
+            "plain" => Some(Format::Plain),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A part's answer or why it has none, and how long solving it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Line {
+    answer: Result<String, String>,
+    duration: Option<Duration>,
+    /// Whether the answer came from a cache, so the part was not solved
+    cached: bool,
+}
+
+/// Prints every part's [answer][PartResult::answer] in a [Format] once all days ran, instead of
+/// the runner's output
+///
+/// Lines are ordered by day and part and only the first run of every part is printed. Parts
+/// without an answer are printed with an `error` instead in [Format::Json] and to stderr in
+/// [Format::Plain]. [Format::Human] leaves the output to the runner and prints nothing.
+#[derive(Debug, Default)]
+pub struct OutputAnalyzer {
+    format: Format,
+    started: bool,
+    forked: bool,
+    start_part: Option<Instant>,
+    /// The last error of the current step, reported before its `after_part` call
+    error: Option<String>,
+    /// Whether the current part's answer came from a cache
+    cached: bool,
+    /// Keyed by (day, part), where `part` is `None` for parsing
+    lines: BTreeMap<(usize, Option<usize>), Line>,
+}
+
+impl OutputAnalyzer {
+    pub fn new(format: Format) -> Self {
+        OutputAnalyzer {
+            format,
+            ..Default::default()
+        }
+    }
+
+    fn print(&mut self) {
+        for ((day, part), line) in std::mem::take(&mut self.lines) {
+            match self.format {
+                Format::Human => {}
+                Format::Plain => match line.answer {
+                    Ok(answer) => println!("{}", escape(&answer)),
+                    Err(error) => match part {
+                        Some(part) => eprintln!("Day {} Part {}: {}", day, part, error),
+                        None => eprintln!("Day {}: {}", day, error),
+                    },
+                },
+                Format::Json => println!("{}", line.to_json(day, part)),
+            }
+        }
+    }
+}
+
+impl Line {
+    fn to_json(&self, day: usize, part: Option<usize>) -> String {
+        let mut json = format!("{{\"day\":{}", day);
+        match part {
+            Some(part) => write!(json, ",\"part\":{}", part).unwrap(),
+            None => json.push_str(",\"part\":null"),
+        }
+        match &self.answer {
+            Ok(answer) => write!(json, ",\"answer\":{}", json_string(answer)).unwrap(),
+            Err(error) => write!(json, ",\"error\":{}", json_string(error)).unwrap(),
+        }
+        if self.cached {
+            json.push_str(",\"cached\":true");
+        } else if let Some(duration) = self.duration {
+            write!(json, ",\"duration_ns\":{}", duration.as_nanos()).unwrap();
+        }
+        json.push('}');
+        json
+    }
+}
+
+/// Quotes and escapes a string for JSON
+pub(crate) fn json_string(value: &str) -> String {
+    let mut json = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+impl Analyzer for OutputAnalyzer {
+    fn before_all(&mut self) {
+        self.started = true;
+    }
+
+    fn after_all(&mut self) {
+        self.print();
+    }
+
+    fn after_day(&mut self, _day: usize) {
+        if !self.started && !self.forked {
+            self.print();
+        }
+    }
+
+    fn after_parse(&mut self, day: usize) {
+        if let Some(error) = self.error.take() {
+            self.lines.entry((day, None)).or_insert(Line {
+                answer: Err(error),
+                duration: None,
+                cached: false,
+            });
+        }
+    }
+
+    fn before_part(&mut self, _day: usize, _part: usize) {
+        self.start_part = Some(Instant::now());
+    }
+
+    fn after_part(&mut self, day: usize, part: usize, result: Option<&PartResult>) {
+        let duration = self.start_part.take().map(|start| start.elapsed());
+        let answer = match result {
+            Some(result) => Ok(result.answer()),
+            None => Err(self.error.take().unwrap_or_else(|| "no result".to_owned())),
+        };
+        let cached = std::mem::take(&mut self.cached);
+        self.lines.entry((day, Some(part))).or_insert(Line {
+            answer,
+            duration,
+            cached,
+        });
+    }
+
+    fn on_error(&mut self, _day: usize, _part: Option<usize>, message: &str) {
+        self.error = Some(message.to_owned());
+    }
+
+    fn on_timeout(&mut self, _day: usize, _part: usize, timeout: Duration) {
+        self.error = Some(format!("timed out after {:?}", timeout));
+    }
+
+    fn on_cache_hit(&mut self, _day: usize, part: Option<usize>) {
+        self.cached = part.is_some();
+    }
+
+    fn prints_results(&self) -> bool {
+        self.format != Format::Human
+    }
+}
+
+impl ParallelAnalyzer for OutputAnalyzer {
+    fn fork(&self) -> Self {
+        OutputAnalyzer {
+            format: self.format,
+            forked: true,
+            ..Default::default()
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (key, line) in other.lines {
+            self.lines.entry(key).or_insert(line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn collect_lines() {
+        let mut analyzer = OutputAnalyzer::new(Format::Json);
+        analyzer.before_all();
+        for run in 0..2 {
+            analyzer.before_part(5, 1);
+            analyzer.after_part(5, 1, Some(&PartResult::new(&format!("CMZ{}", run))));
+            analyzer.before_part(5, 2);
+            analyzer.on_error(5, Some(2), "not \"found\"");
+            analyzer.after_part(5, 2, None);
+        }
+        analyzer.on_error(6, None, "line 1, column 1: expected number");
+        analyzer.after_parse(6);
+        analyzer.before_part(7, 1);
+        analyzer.on_cache_hit(7, Some(1));
+        analyzer.after_part(7, 1, Some(&PartResult::new(&"12")));
+        analyzer.before_part(7, 2);
+        analyzer.after_part(7, 2, Some(&PartResult::new(&"34")));
+
+        let lines = analyzer
+            .lines
+            .iter()
+            .map(|(&(day, part), line)| {
+                let mut line = line.clone();
+                line.duration = line.duration.map(|_| Duration::from_nanos(7));
+                line.to_json(day, part)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                r#"{"day":5,"part":1,"answer":"CMZ0","duration_ns":7}"#,
+                r#"{"day":5,"part":2,"error":"not \"found\"","duration_ns":7}"#,
+                r#"{"day":6,"part":null,"error":"line 1, column 1: expected number"}"#,
+                r#"{"day":7,"part":1,"answer":"12","cached":true}"#,
+                r#"{"day":7,"part":2,"answer":"34","duration_ns":7}"#,
+            ]
+        );
+        assert!(analyzer.prints_results());
+        assert!(!OutputAnalyzer::new(Format::Human).prints_results());
+    }
+
+    #[test]
+    fn escape_json() {
+        assert_eq!(json_string("a\"b\\\n\u{1}"), r#""a\"b\\\n\u0001""#);
+    }
+}
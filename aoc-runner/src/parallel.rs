@@ -0,0 +1,211 @@
+//! Runs multiple days' puzzles in parallel
+//!
+//! Every day is run with its own analyzer, forked from the main analyzer via
+//! [ParallelAnalyzer::fork]. Once all days are done, the day's outputs are printed in order and
+//! their analyzers are merged back into the main analyzer.
+
+use std::{
+    collections::VecDeque,
+    sync::{mpsc, Mutex},
+    thread,
+};
+
+use crate::{
+    cache::Cache,
+    runner::{run_day_to, Output},
+    Analyzer, Day, Results,
+};
+
+/// An [Analyzer] that can be split up to analyze days on different threads
+pub trait ParallelAnalyzer: Analyzer + Send {
+    /// Creates an empty analyzer to use for a single day on a worker thread
+    fn fork(&self) -> Self;
+
+    /// Merges the data of a forked analyzer back into this one
+    fn merge(&mut self, other: Self);
+}
+
+macro_rules! impl_parallel_analyzer_for_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name: ParallelAnalyzer),+> ParallelAnalyzer for ($($name,)+) {
+            fn fork(&self) -> Self {
+                ($(self.$idx.fork(),)+)
+            }
+
+            fn merge(&mut self, other: Self) {
+                $( self.$idx.merge(other.$idx); )+
+            }
+        }
+    };
+}
+
+impl_parallel_analyzer_for_tuple!(A 0);
+impl_parallel_analyzer_for_tuple!(A 0, B 1);
+impl_parallel_analyzer_for_tuple!(A 0, B 1, C 2);
+impl_parallel_analyzer_for_tuple!(A 0, B 1, C 2, D 3);
+impl_parallel_analyzer_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_parallel_analyzer_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+impl<A: ParallelAnalyzer> ParallelAnalyzer for Vec<A> {
+    fn fork(&self) -> Self {
+        self.iter().map(A::fork).collect()
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.iter_mut()
+            .zip(other)
+            .for_each(|(analyzer, other)| analyzer.merge(other));
+    }
+}
+
+impl<A: ParallelAnalyzer> ParallelAnalyzer for Option<A> {
+    fn fork(&self) -> Self {
+        self.as_ref().map(A::fork)
+    }
+
+    fn merge(&mut self, other: Self) {
+        if let (Some(analyzer), Some(other)) = (self, other) {
+            analyzer.merge(other);
+        }
+    }
+}
+
+impl<A: ParallelAnalyzer> ParallelAnalyzer for Box<A> {
+    fn fork(&self) -> Self {
+        Box::new(self.as_ref().fork())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.as_mut().merge(*other);
+    }
+}
+
+type RunFn<'a, A> = Box<dyn FnOnce(&mut A, &mut Output) -> Results + Send + 'a>;
+
+/// A day's puzzle, ready to run on a worker thread
+pub struct Job<'a, A> {
+    day: usize,
+    run: RunFn<'a, A>,
+}
+
+impl<'a, A: Analyzer> Job<'a, A> {
+    /// Creates a job that runs one (`part`) or both parts of `puzzle`, taking answers from
+    /// `cache` if given, see [run_day_with_cache][crate::runner::run_day_with_cache]
+    pub fn new<D: Day>(
+        puzzle: &'a mut D,
+        day: usize,
+        part: Option<usize>,
+        input: &'a str,
+        cache: Option<&'a Cache>,
+    ) -> Self {
+        Job {
+            day,
+            run: Box::new(move |analyzer, out| {
+                run_day_to(puzzle, day, part, input, analyzer, None, cache, out)
+            }),
+        }
+    }
+}
+
+/// Runs all jobs on up to `threads` worker threads and returns their formatted results
+///
+/// Calls `before_all` / `after_all` on `analyzer` around the whole run.
+pub fn run_all<A: ParallelAnalyzer>(
+    jobs: Vec<Job<A>>,
+    analyzer: &mut A,
+    threads: usize,
+) -> Results {
+    let threads = threads.clamp(1, jobs.len().max(1));
+    analyzer.before_all();
+    let queue = jobs
+        .into_iter()
+        .map(|job| (job, analyzer.fork()))
+        .collect::<VecDeque<_>>();
+    let queue = Mutex::new(queue);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || loop {
+                let Some((job, mut day_analyzer)) = queue.lock().unwrap().pop_front() else {
+                    break;
+                };
+                let mut out = match day_analyzer.prints_results() {
+                    true => Output::Silent,
+                    false => Output::Buffer(String::new()),
+                };
+                let results = (job.run)(&mut day_analyzer, &mut out);
+                sender.send((job.day, day_analyzer, out, results)).unwrap();
+            });
+        }
+    });
+    drop(sender);
+
+    let mut days = receiver.into_iter().collect::<Vec<_>>();
+    days.sort_by_key(|(day, ..)| *day);
+
+    let mut results = Results::new();
+    for (_, day_analyzer, out, mut day_results) in days {
+        if let Output::Buffer(out) = out {
+            print!("{}", out);
+        }
+        analyzer.merge(day_analyzer);
+        results.append(&mut day_results);
+    }
+    analyzer.after_all();
+
+    results
+}
+
+/// The number of threads to use if not given explicitly
+pub fn default_threads() -> usize {
+    thread::available_parallelism()
+        .map(usize::from)
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ParseError, TimeAnalyzer};
+
+    #[derive(Default)]
+    struct Puzzle(u32);
+
+    impl Day for Puzzle {
+        type Result1 = u32;
+        type Result2 = u32;
+
+        fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+            self.0 = input.len() as u32;
+            Ok(())
+        }
+
+        fn part1(&mut self) -> Self::Result1 {
+            self.0
+        }
+
+        fn part2(&mut self) -> Self::Result2 {
+            self.0 * 2
+        }
+    }
+
+    #[test]
+    fn run_all_days() {
+        let mut puzzles: Vec<Puzzle> = (0..4).map(|_| Puzzle::default()).collect();
+        let inputs = ["a", "bb", "ccc", "dddd"];
+        let jobs = puzzles
+            .iter_mut()
+            .zip(inputs)
+            .enumerate()
+            .map(|(idx, (puzzle, input))| Job::new(puzzle, idx + 1, None, input, None))
+            .collect();
+
+        let results = run_all(jobs, &mut TimeAnalyzer::new(), 3);
+        assert_eq!(results.len(), 8);
+        assert_eq!(results[&(3, 1)], "3");
+        assert_eq!(results[&(4, 2)], "8");
+    }
+}
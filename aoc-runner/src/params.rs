@@ -0,0 +1,224 @@
+//! Runtime parameters of days, e.g. the row to check in a puzzle whose example uses another row
+//!
+//! A day keeps its parameters in a struct deriving `Params`, whose [Default] values are the ones
+//! of the real puzzle, and returns it from [Day::params]. The runner sets them from
+//! [ParamValues], which are read from the command line or a small subset of TOML,
+//! with one table per day:
+//!
+//! ```toml
+//! # day 15
+//! [15]
+//! row = 10
+//! area = 20
+//! ```
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{
+    toml::{self, Entry},
+    Day, DynDay, ParseError,
+};
+
+/// A day's typed parameters
+pub trait Params {
+    /// Sets a parameter from its textual value
+    ///
+    /// Returns what was expected instead, if there is no such parameter or the value is invalid.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+
+    /// All parameters with their current values, in the order they are declared
+    fn values(&self) -> Vec<(&'static str, String)>;
+}
+
+/// A parameter that could not be set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamError {
+    pub day: usize,
+    pub key: String,
+    pub expected: String,
+}
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, parameter '{}': expected {}",
+            self.day, self.key, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// Parameter values to set on days, keyed by day and then by parameter
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ParamValues(BTreeMap<usize, BTreeMap<String, String>>);
+
+impl ParamValues {
+    pub fn new() -> Self {
+        ParamValues::default()
+    }
+
+    /// Parses parameter values from a TOML file's content
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut values = ParamValues::new();
+        for Entry { day, key, value } in toml::parse(input, "'<parameter> = <value>'")? {
+            values.insert(day, key, value);
+        }
+
+        Ok(values)
+    }
+
+    /// Parses a single value given as `<day>.<parameter>=<value>`, e.g. `15.row=10`
+    pub fn parse_arg(arg: &str) -> Option<(usize, &str, &str)> {
+        let (name, value) = arg.split_once('=')?;
+        let (day, key) = name.split_once('.')?;
+        Some((day.trim().parse().ok()?, key.trim(), value.trim()))
+    }
+
+    pub fn insert(&mut self, day: usize, key: impl Into<String>, value: impl Into<String>) {
+        self.0
+            .entry(day)
+            .or_default()
+            .insert(key.into(), value.into());
+    }
+
+    /// Adds all values of `other`, replacing values that are set in both
+    pub fn extend(&mut self, other: ParamValues) {
+        for (day, values) in other.0 {
+            self.0.entry(day).or_default().extend(values);
+        }
+    }
+
+    /// The values given for a day, ordered by parameter
+    pub fn get(&self, day: usize) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .get(&day)
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Sets all values given for `day` on a puzzle
+    pub fn apply(&self, puzzle: &mut dyn DynDay, day: usize) -> Result<(), ParamError> {
+        let mut values = self.get(day).peekable();
+        if values.peek().is_none() {
+            return Ok(());
+        }
+        let Some(params) = puzzle.params() else {
+            let (key, _) = values.next().unwrap_or_default();
+            return Err(ParamError {
+                day,
+                key: key.to_owned(),
+                expected: "no parameters for this day".to_owned(),
+            });
+        };
+        for (key, value) in values {
+            params.set(key, value).map_err(|expected| ParamError {
+                day,
+                key: key.to_owned(),
+                expected,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// A [Default] instance of `D` with the same parameters as `puzzle`
+pub(crate) fn fresh<D: Day>(puzzle: &mut D) -> D {
+    let values = puzzle.params().map(|params| params.values());
+    with_params(&values.unwrap_or_default())
+}
+
+/// A [Default] instance of `D` with the given parameter [values][Params::values]
+pub(crate) fn with_params<D: Day>(values: &[(&str, String)]) -> D {
+    let mut puzzle = D::default();
+    if let Some(params) = puzzle.params() {
+        for (key, value) in values {
+            params
+                .set(key, value)
+                .expect("parameters accept their own values");
+        }
+    }
+    puzzle
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct Puzzle(Row);
+
+    struct Row(usize);
+
+    impl Default for Row {
+        fn default() -> Self {
+            Row(2000000)
+        }
+    }
+
+    impl Params for Row {
+        fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+            match key {
+                "row" => self.0 = value.parse().map_err(|_| "usize".to_owned())?,
+                _ => return Err("one of row".to_owned()),
+            }
+            Ok(())
+        }
+
+        fn values(&self) -> Vec<(&'static str, String)> {
+            vec![("row", self.0.to_string())]
+        }
+    }
+
+    impl Day for Puzzle {
+        type Result1 = usize;
+        type Result2 = usize;
+
+        fn part1(&mut self) -> Self::Result1 {
+            self.0 .0
+        }
+
+        fn part2(&mut self) -> Self::Result2 {
+            self.0 .0
+        }
+
+        fn params(&mut self) -> Option<&mut dyn Params> {
+            Some(&mut self.0)
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let values = ParamValues::parse("# example\n[15]\nrow = 10\narea = \"20\"\n").unwrap();
+        assert_eq!(
+            values.get(15).collect::<Vec<_>>(),
+            vec![("area", "20"), ("row", "10")]
+        );
+        assert_eq!(values.get(1).count(), 0);
+        assert_eq!(
+            ParamValues::parse("row = 10"),
+            Err(ParseError::new(1, 1, "'[<day>]'"))
+        );
+        assert_eq!(ParamValues::parse_arg("15.row=10"), Some((15, "row", "10")));
+        assert_eq!(ParamValues::parse_arg("row=10"), None);
+    }
+
+    #[test]
+    fn apply() {
+        let mut puzzle = Puzzle::default();
+        let mut values = ParamValues::new();
+        values.insert(15, "row", "10");
+        values.apply(&mut puzzle, 15).unwrap();
+        assert_eq!(puzzle.part1(), 10);
+        assert_eq!(fresh(&mut puzzle).part1(), 10);
+
+        values.insert(15, "column", "10");
+        assert_eq!(
+            values.apply(&mut puzzle, 15).map_err(|err| err.to_string()),
+            Err("day 15, parameter 'column': expected one of row".to_owned())
+        );
+        assert!(values.apply(&mut puzzle, 1).is_ok());
+    }
+}
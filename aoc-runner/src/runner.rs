@@ -0,0 +1,593 @@
+//! Runs a single day's puzzle while reporting to an [Analyzer]
+
+use std::{
+    any::Any,
+    fmt::{Display, Write},
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    analyzer::PartResult,
+    cache::{input_hash, Cache},
+    input::normalize,
+    params::{fresh, with_params},
+    trace, Analyzer, Answer, Day, DayInfo, ParamValues, Results, SolveError,
+};
+
+/// Where the human readable output of a run goes
+pub(crate) enum Output {
+    /// Print directly, using [Day::print_part1] / [Day::print_part2] for results
+    Stdout,
+    /// Collect all lines, e.g. to print them later in order
+    Buffer(String),
+    /// Discard all output
+    Silent,
+}
+
+impl Output {
+    /// Prints directly, unless `analyzer` prints the results itself, see
+    /// [Analyzer::prints_results]
+    pub(crate) fn stdout_unless(analyzer: &impl Analyzer) -> Self {
+        if analyzer.prints_results() {
+            Output::Silent
+        } else {
+            Output::Stdout
+        }
+    }
+
+    fn line(&mut self, line: impl Display) {
+        match self {
+            Output::Stdout => println!("{}", line),
+            Output::Buffer(buffer) => {
+                writeln!(buffer, "{}", line).unwrap();
+            }
+            Output::Silent => {}
+        }
+    }
+}
+
+/// Runs one or both parts of a day's puzzle and returns their formatted results
+///
+/// Parse errors as well as panics while parsing or solving a part are printed and reported via
+/// [Analyzer::on_error] instead of aborting the run. If the input could not be parsed, no part
+/// is run. Nothing is printed if the analyzer [prints the results][Analyzer::prints_results].
+///
+/// The input is [normalized][crate::input] for the day before it is parsed.
+pub fn run_day<D: Day>(
+    puzzle: &mut D,
+    day: usize,
+    part: Option<usize>,
+    input: &str,
+    analyzer: &mut impl Analyzer,
+) -> Results {
+    let mut out = Output::stdout_unless(analyzer);
+    run_day_to(puzzle, day, part, input, analyzer, None, None, &mut out)
+}
+
+/// Like [run_day], but stops waiting for a part once it ran for longer than `timeout`
+///
+/// With a timeout, each part runs on a worker thread, which is abandoned if it does not finish
+/// in time. The timeout is reported via [Analyzer::on_timeout] and the puzzle is parsed anew, so
+/// the remaining part can still run.
+pub fn run_day_with_timeout<D: Day>(
+    puzzle: &mut D,
+    day: usize,
+    part: Option<usize>,
+    input: &str,
+    analyzer: &mut impl Analyzer,
+    timeout: Option<Duration>,
+) -> Results {
+    run_day_with_cache(puzzle, day, part, input, analyzer, timeout, None)
+}
+
+/// Like [run_day_with_timeout], but takes the answers of parts from `cache` if they were solved
+/// for the same input and parameters before, and adds new answers to it
+///
+/// Cached parts are not solved and reported via [Analyzer::on_cache_hit]. If all selected parts
+/// are cached, the input is not parsed either.
+pub fn run_day_with_cache<D: Day>(
+    puzzle: &mut D,
+    day: usize,
+    part: Option<usize>,
+    input: &str,
+    analyzer: &mut impl Analyzer,
+    timeout: Option<Duration>,
+    cache: Option<&Cache>,
+) -> Results {
+    let mut out = Output::stdout_unless(analyzer);
+    run_day_to(puzzle, day, part, input, analyzer, timeout, cache, &mut out)
+}
+
+/// Runs one or both parts of a day's puzzle `runs` times, each time on a fresh [Default]
+/// instance with the [params][Day::params] of `puzzle`, and returns the formatted results of the
+/// first run
+///
+/// Only the first run's output is printed, but `analyzer` sees every run.
+pub fn run_day_repeated<D: Day>(
+    puzzle: &mut D,
+    day: usize,
+    part: Option<usize>,
+    input: &str,
+    analyzer: &mut impl Analyzer,
+    runs: usize,
+) -> Results {
+    let results = run_day(&mut fresh(puzzle), day, part, input, analyzer);
+    for _ in 1..runs {
+        run_day_to(
+            &mut fresh(puzzle),
+            day,
+            part,
+            input,
+            analyzer,
+            None,
+            None,
+            &mut Output::Silent,
+        );
+    }
+    results
+}
+
+/// Solves a part of one of `days` on `input` and returns its formatted answer, see
+/// [PartResult::answer]
+///
+/// Nothing is printed or analyzed; parse errors and panics are returned instead. The day runs on
+/// a fresh instance with the given parameters.
+pub fn solve(
+    days: &[DayInfo],
+    day: usize,
+    part: usize,
+    input: &str,
+    params: &ParamValues,
+) -> Result<String, SolveError> {
+    let info = days
+        .iter()
+        .find(|info| info.day == day)
+        .ok_or(SolveError::InvalidDay(day))?;
+    if part != 1 && part != 2 {
+        return Err(SolveError::InvalidPart(part));
+    }
+
+    let mut puzzle = (info.new)();
+    params.apply(&mut *puzzle, day).map_err(SolveError::Param)?;
+    let input = normalize(input, info.trim_input);
+    catch_unwind(AssertUnwindSafe(|| {
+        puzzle.parse(&input).map_err(SolveError::Parse)?;
+        Ok(puzzle.answer(part))
+    }))
+    .unwrap_or_else(|panic| Err(SolveError::Panicked(panic_message(&*panic))))
+}
+
+/// Like [run_day_with_cache], but writes all output to `out`
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn run_day_to<D: Day>(
+    puzzle: &mut D,
+    day: usize,
+    part: Option<usize>,
+    input: &str,
+    analyzer: &mut impl Analyzer,
+    timeout: Option<Duration>,
+    cache: Option<&Cache>,
+    out: &mut Output,
+) -> Results {
+    let parts: &[usize] = match part {
+        Some(1) => &[1],
+        Some(2) => &[2],
+        None => &[1, 2],
+        Some(part) => panic!("Invalid part: {}. Valid parts are: 1,2", part),
+    };
+
+    out.line(format_args!("Day {}", day));
+    let mut results = Results::new();
+    let input = &*normalize(input, D::TRIM_INPUT);
+    let params = puzzle.params().map(|params| params.values());
+    let params = params.unwrap_or_default();
+    let input_hash = cache.map(|_| input_hash(input, &params));
+    let cached = |part: usize| cache?.get(day, part, input_hash?);
+
+    analyzer.before_day(day);
+    analyzer.before_parse(day);
+    let error = if parts.iter().all(|&part| cached(part).is_some()) {
+        analyzer.on_cache_hit(day, None);
+        None
+    } else {
+        match catch_unwind(AssertUnwindSafe(|| puzzle.parse(input))) {
+            Ok(Ok(())) => None,
+            Ok(Err(err)) => {
+                out.line(format_args!(" - Parse error: {}", err));
+                Some(err.to_string())
+            }
+            Err(panic) => {
+                let message = panic_message(&*panic);
+                out.line(format_args!(" - Parse panicked: {}", message));
+                Some(message)
+            }
+        }
+    };
+    if let Some(message) = error {
+        analyzer.on_error(day, None, &message);
+        analyzer.after_parse(day);
+        analyzer.after_day(day);
+        return results;
+    }
+    analyzer.after_parse(day);
+
+    for &part in parts {
+        if let Some(answer) = cached(part) {
+            analyzer.before_part(day, part);
+            analyzer.on_cache_hit(day, Some(part));
+            analyzer.after_part(day, part, Some(&PartResult::new(&answer)));
+            out.line(format_args!(" - Part {}: {} (cached)", part, answer));
+            results.insert((day, part), answer);
+            continue;
+        }
+
+        let timed_out = if part == 1 {
+            let solve = Part(D::part1, D::print_part1);
+            run_part(puzzle, day, 1, analyzer, &mut results, out, solve, timeout)
+        } else {
+            let solve = Part(D::part2, D::print_part2);
+            run_part(puzzle, day, 2, analyzer, &mut results, out, solve, timeout)
+        };
+        if timed_out {
+            // The puzzle was left behind on the worker thread; this input parsed fine before
+            *puzzle = with_params(&params);
+            let _ = catch_unwind(AssertUnwindSafe(|| puzzle.parse(input)));
+        }
+        if let (Some(cache), Some(input_hash), Some(answer)) =
+            (cache, input_hash, results.get(&(day, part)))
+        {
+            cache.insert(day, part, input_hash, answer.clone());
+        }
+    }
+
+    analyzer.after_day(day);
+    results
+}
+
+/// A part's solve and print functions
+struct Part<D, R>(
+    fn(&mut D) -> R,
+    fn(&D, R, &mut dyn Write) -> std::fmt::Result,
+);
+
+/// How solving a part ended
+enum Outcome<R> {
+    Solved(R),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+/// Runs a single part and returns whether it timed out
+#[allow(clippy::too_many_arguments)]
+fn run_part<D: Day, R: Answer>(
+    puzzle: &mut D,
+    day: usize,
+    part: usize,
+    analyzer: &mut impl Analyzer,
+    results: &mut Results,
+    out: &mut Output,
+    Part(solve, print): Part<D, R>,
+    timeout: Option<Duration>,
+) -> bool {
+    analyzer.before_part(day, part);
+    match solve_part(puzzle, solve, timeout) {
+        Outcome::Solved(result) => {
+            let part_result = PartResult::new(&result);
+            analyzer.after_part(day, part, Some(&part_result));
+            results.insert((day, part), part_result.answer());
+            match out {
+                Output::Stdout => {
+                    let mut line = String::new();
+                    print(puzzle, result, &mut line).unwrap();
+                    print!("{}", line);
+                }
+                Output::Buffer(buffer) => print(puzzle, result, buffer).unwrap(),
+                Output::Silent => {}
+            }
+        }
+        Outcome::Panicked(message) => {
+            out.line(format_args!(" - Part {} panicked: {}", part, message));
+            analyzer.on_error(day, Some(part), &message);
+            analyzer.after_part(day, part, None);
+        }
+        Outcome::TimedOut(timeout) => {
+            out.line(format_args!(
+                " - Part {} timed out after {:?}",
+                part, timeout
+            ));
+            analyzer.on_timeout(day, part, timeout);
+            analyzer.after_part(day, part, None);
+            return true;
+        }
+    }
+    false
+}
+
+/// Solves a part, on a worker thread if there is a `timeout`
+fn solve_part<D: Day, R: Send + 'static>(
+    puzzle: &mut D,
+    solve: fn(&mut D) -> R,
+    timeout: Option<Duration>,
+) -> Outcome<R> {
+    let Some(timeout) = timeout else {
+        return match catch_unwind(AssertUnwindSafe(|| solve(puzzle))) {
+            Ok(result) => Outcome::Solved(result),
+            Err(panic) => Outcome::Panicked(panic_message(&*panic)),
+        };
+    };
+
+    let mut owned = std::mem::take(puzzle);
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::spawn(move || {
+        let result = catch_unwind(AssertUnwindSafe(|| solve(&mut owned)));
+        // The receiver is gone if the part timed out
+        let _ = sender.send((owned, result));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok((owned, result)) => {
+            *puzzle = owned;
+            match result {
+                Ok(result) => Outcome::Solved(result),
+                Err(panic) => Outcome::Panicked(panic_message(&*panic)),
+            }
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            trace::abandon(worker.thread().id());
+            Outcome::TimedOut(timeout)
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            Outcome::Panicked("worker thread terminated unexpectedly".to_owned())
+        }
+    }
+}
+
+/// Extracts the message of a panic's payload
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Params, ParseError};
+
+    #[derive(Default)]
+    struct Puzzle(u32);
+
+    impl Day for Puzzle {
+        type Result1 = u32;
+        type Result2 = u32;
+
+        fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+            self.0 = input
+                .parse()
+                .map_err(|_| ParseError::at(input, input, "number"))?;
+            Ok(())
+        }
+
+        fn part1(&mut self) -> Self::Result1 {
+            panic!("Not found")
+        }
+
+        fn part2(&mut self) -> Self::Result2 {
+            self.0 * 2
+        }
+
+        fn print_part2(&self, result: Self::Result2, out: &mut dyn Write) -> std::fmt::Result {
+            writeln!(out, " - Part 2: {} doubled is {}", self.0, result)
+        }
+    }
+
+    #[derive(Default)]
+    struct ErrorAnalyzer(Vec<(usize, Option<usize>, String)>);
+
+    impl Analyzer for ErrorAnalyzer {
+        fn on_error(&mut self, day: usize, part: Option<usize>, message: &str) {
+            self.0.push((day, part, message.to_owned()));
+        }
+    }
+
+    #[derive(Default)]
+    struct ResultAnalyzer(Vec<(usize, usize, Option<String>)>);
+
+    impl Analyzer for ResultAnalyzer {
+        fn after_part(&mut self, day: usize, part: usize, result: Option<&PartResult>) {
+            self.0.push((day, part, result.map(PartResult::answer)));
+        }
+    }
+
+    #[test]
+    fn pass_results_to_analyzer() {
+        let mut analyzer = ResultAnalyzer::default();
+        run_day(&mut Puzzle::default(), 3, None, "21", &mut analyzer);
+        assert_eq!(
+            analyzer.0,
+            vec![(3, 1, None), (3, 2, Some("42".to_owned()))]
+        );
+    }
+
+    #[test]
+    fn isolate_panicking_part() {
+        let mut analyzer = ErrorAnalyzer::default();
+        let results = run_day(&mut Puzzle::default(), 3, None, "21", &mut analyzer);
+        assert_eq!(results, Results::from([((3, 2), "42".to_owned())]));
+        assert_eq!(analyzer.0, vec![(3, Some(1), "Not found".to_owned())]);
+    }
+
+    #[test]
+    fn repeat_runs() {
+        let mut analyzer = ResultAnalyzer::default();
+        let results = run_day_repeated(&mut Puzzle::default(), 3, Some(2), "21", &mut analyzer, 3);
+        assert_eq!(results, Results::from([((3, 2), "42".to_owned())]));
+        assert_eq!(analyzer.0, vec![(3, 2, Some("42".to_owned())); 3]);
+    }
+
+    #[derive(Default)]
+    struct SlowPuzzle(u32, Factor);
+
+    /// The factor of part 2
+    struct Factor(u32);
+
+    impl Default for Factor {
+        fn default() -> Self {
+            Factor(2)
+        }
+    }
+
+    impl Params for Factor {
+        fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+            match key {
+                "factor" => self.0 = value.parse().map_err(|_| "u32".to_owned())?,
+                _ => return Err("one of factor".to_owned()),
+            }
+            Ok(())
+        }
+
+        fn values(&self) -> Vec<(&'static str, String)> {
+            vec![("factor", self.0.to_string())]
+        }
+    }
+
+    impl Day for SlowPuzzle {
+        type Result1 = u32;
+        type Result2 = u32;
+
+        fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+            self.0 = input.len() as u32;
+            Ok(())
+        }
+
+        fn part1(&mut self) -> Self::Result1 {
+            thread::sleep(Duration::from_secs(10));
+            self.0
+        }
+
+        fn part2(&mut self) -> Self::Result2 {
+            self.0 * self.1 .0
+        }
+
+        fn params(&mut self) -> Option<&mut dyn Params> {
+            Some(&mut self.1)
+        }
+    }
+
+    #[derive(Default)]
+    struct TimeoutAnalyzer(Vec<(usize, usize)>);
+
+    impl Analyzer for TimeoutAnalyzer {
+        fn on_timeout(&mut self, day: usize, part: usize, _timeout: Duration) {
+            self.0.push((day, part));
+        }
+    }
+
+    #[test]
+    fn time_out_part() {
+        let mut analyzer = TimeoutAnalyzer::default();
+        let timeout = Some(Duration::from_millis(50));
+        let results = run_day_with_timeout(
+            &mut SlowPuzzle::default(),
+            4,
+            None,
+            "abc",
+            &mut analyzer,
+            timeout,
+        );
+        assert_eq!(results, Results::from([((4, 2), "6".to_owned())]));
+        assert_eq!(analyzer.0, vec![(4, 1)]);
+    }
+
+    #[test]
+    fn keep_params_after_timeout() {
+        let mut puzzle = SlowPuzzle::default();
+        puzzle.1 .0 = 5;
+        let timeout = Some(Duration::from_millis(50));
+        let analyzer = &mut TimeoutAnalyzer::default();
+        let results = run_day_with_timeout(&mut puzzle, 4, None, "abc", analyzer, timeout);
+        assert_eq!(results, Results::from([((4, 2), "15".to_owned())]));
+        assert_eq!(puzzle.1 .0, 5);
+    }
+
+    #[test]
+    fn buffer_printed_results() {
+        let mut out = Output::Buffer(String::new());
+        let puzzle = &mut Puzzle::default();
+        let analyzer = &mut ErrorAnalyzer::default();
+        run_day_to(puzzle, 3, Some(2), "21", analyzer, None, None, &mut out);
+        let Output::Buffer(buffer) = out else {
+            unreachable!()
+        };
+        assert_eq!(buffer, "Day 3\n - Part 2: 21 doubled is 42\n");
+    }
+
+    #[test]
+    fn skip_parts_on_parse_error() {
+        let mut analyzer = ErrorAnalyzer::default();
+        let results = run_day(&mut Puzzle::default(), 3, None, "x", &mut analyzer);
+        assert!(results.is_empty());
+        assert_eq!(
+            analyzer.0,
+            vec![(3, None, "line 1, column 1: expected number".to_owned())]
+        );
+    }
+
+    #[derive(Default)]
+    struct CacheAnalyzer(Vec<(usize, Option<usize>)>);
+
+    impl Analyzer for CacheAnalyzer {
+        fn on_cache_hit(&mut self, day: usize, part: Option<usize>) {
+            self.0.push((day, part));
+        }
+    }
+
+    #[test]
+    fn take_answers_from_cache() {
+        let cache = Cache::load(std::env::temp_dir().join("aoc-runner-unsaved.csv"), 0);
+        cache.insert(3, 1, input_hash("21", &[]), "7");
+        let expected = Results::from([((3, 1), "7".to_owned()), ((3, 2), "42".to_owned())]);
+
+        let mut analyzer = CacheAnalyzer::default();
+        let run = |analyzer: &mut CacheAnalyzer| {
+            let puzzle = &mut Puzzle::default();
+            run_day_with_cache(puzzle, 3, None, "21\n", analyzer, None, Some(&cache))
+        };
+        assert_eq!(run(&mut analyzer), expected);
+        assert_eq!(analyzer.0, vec![(3, Some(1))]);
+
+        let mut analyzer = CacheAnalyzer::default();
+        assert_eq!(run(&mut analyzer), expected);
+        assert_eq!(analyzer.0, vec![(3, None), (3, Some(1)), (3, Some(2))]);
+    }
+
+    #[test]
+    fn solve_parts() {
+        let days = [DayInfo::of::<Puzzle>(3)];
+        let params = ParamValues::new();
+        assert_eq!(solve(&days, 3, 2, "21", &params), Ok("42".to_owned()));
+        assert_eq!(
+            solve(&days, 3, 1, "21", &params),
+            Err(SolveError::Panicked("Not found".to_owned()))
+        );
+        assert_eq!(
+            solve(&days, 3, 2, "x", &params),
+            Err(SolveError::Parse(ParseError::new(1, 1, "number")))
+        );
+        assert_eq!(
+            solve(&days, 4, 2, "21", &params),
+            Err(SolveError::InvalidDay(4))
+        );
+        assert_eq!(
+            solve(&days, 3, 3, "21", &params),
+            Err(SolveError::InvalidPart(3))
+        );
+    }
+}
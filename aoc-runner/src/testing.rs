@@ -0,0 +1,133 @@
+//! Regression checks for single parts, used by the tests generated by `#[derive(AoC)]`
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    input::{self, normalize},
+    Answers, Day, PartResult, Verdict,
+};
+
+/// Name of the answers file in the input directory
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Why a part cannot be checked against the input file `<dir>/NN.txt`: the input file, the
+/// answers file `<dir>/answers.toml` or the part's answer is missing
+///
+/// Inputs are usually not committed, so [verify_input] skips these parts.
+pub fn missing_input(day: usize, part: usize, dir: impl AsRef<Path>) -> Option<String> {
+    let dir = dir.as_ref();
+    let input_file = input_file(day, dir);
+    let answers_file = dir.join(ANSWERS_FILE);
+    if !input_file.is_file() {
+        Some(format!("no input file {}", input_file.display()))
+    } else if !answers_file.is_file() {
+        Some(format!("no answers file {}", answers_file.display()))
+    } else if read_answers(&answers_file).get(day, part).is_none() {
+        Some(format!("no answer for day {} part {}", day, part))
+    } else {
+        None
+    }
+}
+
+/// Solves a part of `D` on the input file `<dir>/NN.txt` and panics if the result differs from
+/// the answer in `<dir>/answers.toml`
+///
+/// Parts whose input or answer is [missing][missing_input] are skipped with a message on stderr.
+pub fn verify_input<D: Day>(day: usize, part: usize, dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    if let Some(reason) = missing_input(day, part, dir) {
+        eprintln!("Day {} Part {}: skipped, {}", day, part, reason);
+        return;
+    }
+    let answers = read_answers(&dir.join(ANSWERS_FILE));
+    let input = input::read(input_file(day, dir))
+        .unwrap_or_else(|err| panic!("Day {}: could not read input: {}", day, err));
+
+    let mut puzzle = D::default();
+    if let Err(err) = puzzle.parse(&normalize(&input, D::TRIM_INPUT)) {
+        panic!("Day {} could not be parsed: {}", day, err);
+    }
+    let actual = match part {
+        1 => PartResult::new(&puzzle.part1()).answer(),
+        2 => PartResult::new(&puzzle.part2()).answer(),
+        _ => panic!("Invalid part: {}. Valid parts are: 1,2", part),
+    };
+    let verdict = answers.verify(day, part, Some(&actual));
+    if verdict != Verdict::Pass {
+        panic!("Day {} Part {}: {}", day, part, verdict);
+    }
+}
+
+fn input_file(day: usize, dir: &Path) -> PathBuf {
+    dir.join(format!("{:0>2}.txt", day))
+}
+
+/// Reads an answers file, which is empty if it cannot be read and panics if it is invalid
+fn read_answers(path: &Path) -> Answers {
+    let content = std::fs::read_to_string(path).unwrap_or_default();
+    Answers::parse(&content)
+        .unwrap_or_else(|err| panic!("invalid answers file '{}': {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ParseError;
+
+    #[derive(Default)]
+    struct Puzzle(u32);
+
+    impl Day for Puzzle {
+        type Result1 = u32;
+        type Result2 = u32;
+
+        fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+            self.0 = input
+                .parse()
+                .map_err(|_| ParseError::at(input, input, "number"))?;
+            Ok(())
+        }
+
+        fn part1(&mut self) -> Self::Result1 {
+            self.0
+        }
+
+        fn part2(&mut self) -> Self::Result2 {
+            self.0 * 2
+        }
+    }
+
+    #[test]
+    fn check_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-testing-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("01.txt"), "21\n").unwrap();
+        assert_eq!(
+            missing_input(1, 1, &dir),
+            Some(format!(
+                "no answers file {}",
+                dir.join(ANSWERS_FILE).display()
+            ))
+        );
+
+        std::fs::write(dir.join(ANSWERS_FILE), "[1]\npart1 = 21\n").unwrap();
+        assert_eq!(missing_input(1, 1, &dir), None);
+        assert_eq!(
+            missing_input(1, 2, &dir),
+            Some("no answer for day 1 part 2".to_owned())
+        );
+        verify_input::<Puzzle>(1, 1, &dir);
+        // Skipped, as there is no answer
+        verify_input::<Puzzle>(1, 2, &dir);
+
+        std::fs::write(dir.join(ANSWERS_FILE), "[1]\npart1 = 21\npart2 = 41\n").unwrap();
+        let wrong = std::panic::catch_unwind(|| verify_input::<Puzzle>(1, 2, &dir));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(wrong.is_err());
+    }
+
+    #[test]
+    fn skip_missing_input() {
+        verify_input::<Puzzle>(1, 1, "does-not-exist");
+    }
+}
@@ -0,0 +1,131 @@
+//! The small subset of TOML that answers and parameter files are written in
+//!
+//! A file has one table per day, whose keys are integers or basic strings:
+//!
+//! ```toml
+//! # day 1
+//! [1]
+//! part1 = 24000
+//! part2 = "45000"
+//! ```
+
+use crate::ParseError;
+
+/// A `key = value` line of a day's table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Entry<'a> {
+    pub day: usize,
+    /// The key, borrowed from the input so errors can point at it
+    pub key: &'a str,
+    pub value: String,
+}
+
+/// Parses all entries of all days' tables, in the order they are written
+///
+/// `entry` describes the expected `key = value` lines in errors, e.g. `'part<n> = <answer>'`.
+pub(crate) fn parse<'a>(input: &'a str, entry: &str) -> Result<Vec<Entry<'a>>, ParseError> {
+    let mut entries = vec![];
+    let mut day = None;
+    for line in input.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(table) = trimmed.strip_prefix('[') {
+            let name = table
+                .strip_suffix(']')
+                .ok_or_else(|| ParseError::at(input, line, "'[<day>]'"))?
+                .trim();
+            day = Some(
+                name.parse::<usize>()
+                    .map_err(|_| ParseError::at(input, name, "day number"))?,
+            );
+            continue;
+        }
+
+        let day = day.ok_or_else(|| ParseError::at(input, line, "'[<day>]'"))?;
+        let (key, value) = trimmed
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(input, line, entry))?;
+        let value = value.trim();
+        let value = parse_value(value)
+            .ok_or_else(|| ParseError::at(input, value, "integer or quoted string"))?;
+        entries.push(Entry {
+            day,
+            key: key.trim(),
+            value,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Parses an integer or a basic string
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(string) = value.strip_prefix('"') {
+        let mut answer = String::new();
+        let mut chars = string.chars();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => answer.push(match chars.next()? {
+                    'n' => '\n',
+                    't' => '\t',
+                    '"' => '"',
+                    '\\' => '\\',
+                    _ => return None,
+                }),
+                c => answer.push(c),
+            }
+        }
+        let rest = chars.as_str().trim();
+        (rest.is_empty() || rest.starts_with('#')).then_some(answer)
+    } else {
+        let value = value.split('#').next().unwrap_or_default().trim();
+        value.parse::<i128>().ok().map(|_| value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_tables() {
+        let entries = parse("# comment\n[1]\na = 1 # one\n\n[ 5 ]\nb = \"x\\\"y\"\n", "").unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                Entry {
+                    day: 1,
+                    key: "a",
+                    value: "1".to_owned()
+                },
+                Entry {
+                    day: 5,
+                    key: "b",
+                    value: "x\"y".to_owned()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        let entry = "'<key> = <value>'";
+        assert_eq!(
+            parse("a = 1", entry),
+            Err(ParseError::new(1, 1, "'[<day>]'"))
+        );
+        assert_eq!(
+            parse("[one]", entry),
+            Err(ParseError::new(1, 2, "day number"))
+        );
+        assert_eq!(parse("[1]\na", entry), Err(ParseError::new(2, 1, entry)));
+        assert_eq!(
+            parse("[1]\na = \"b", entry),
+            Err(ParseError::new(2, 5, "integer or quoted string"))
+        );
+    }
+}
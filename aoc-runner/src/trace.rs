@@ -0,0 +1,409 @@
+//! Traces of a run in the Chrome Trace Event Format, to inspect it in a trace viewer like
+//! `chrome://tracing` or [Perfetto](https://ui.perfetto.dev)
+//!
+//! [TraceAnalyzer] turns the analyzer hooks into nested spans: the run, years, days, parsing and
+//! parts. Days can mark inner phases as spans too, which are only recorded while a
+//! [TraceAnalyzer] exists. Spans of parts that timed out are left out, as their worker threads
+//! are abandoned mid-run:
+//!
+//! ```
+//! fn part2(valves: &[u32]) -> u32 {
+//!     let distances = aoc_runner::trace::in_span("shortest_distances", || valves.to_vec());
+//!     let _span = aoc_runner::trace::span("dfs");
+//!     distances.iter().sum()
+//! }
+//! # assert_eq!(part2(&[1, 2]), 3);
+//! ```
+
+use std::{
+    fmt::Write,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering::Relaxed},
+        Mutex, OnceLock,
+    },
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
+
+use crate::{output::json_string, Analyzer, ParallelAnalyzer, PartResult};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// The start of all timestamps
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+/// Events of [span]s with the threads that recorded them, which may be any thread
+static SPANS: Mutex<Vec<(ThreadId, Event)>> = Mutex::new(Vec::new());
+/// Worker threads of parts that timed out, whose spans are left out
+static ABANDONED: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+static NEXT_THREAD: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Small numbers identifying threads in the trace
+    static THREAD: u64 = NEXT_THREAD.fetch_add(1, Relaxed);
+}
+
+/// A Trace Event Format event that begins (`B`) or ends (`E`) a span, is a complete span with a
+/// duration (`X`), or marks an instant (`i`)
+#[derive(Debug, Clone, PartialEq)]
+struct Event {
+    name: String,
+    category: &'static str,
+    phase: char,
+    /// Microseconds since [EPOCH]
+    timestamp: f64,
+    /// Microseconds a complete span took
+    duration: Option<f64>,
+    thread: u64,
+    args: Vec<(&'static str, String)>,
+}
+
+impl Event {
+    fn now(name: impl Into<String>, category: &'static str, phase: char) -> Self {
+        let epoch = *EPOCH.get_or_init(Instant::now);
+        Event {
+            name: name.into(),
+            category,
+            phase,
+            timestamp: micros(epoch.elapsed()),
+            duration: None,
+            thread: THREAD.with(|thread| *thread),
+            args: vec![],
+        }
+    }
+
+    fn arg(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.args.push((key, value.to_string()));
+        self
+    }
+
+    fn to_json(&self) -> String {
+        let mut json = format!(
+            "{{\"name\":{},\"cat\":\"{}\",\"ph\":\"{}\",\"ts\":{:.3},\"pid\":1,\"tid\":{}",
+            json_string(&self.name),
+            self.category,
+            self.phase,
+            self.timestamp,
+            self.thread
+        );
+        if let Some(duration) = self.duration {
+            write!(json, ",\"dur\":{:.3}", duration).unwrap();
+        }
+        if self.phase == 'i' {
+            json.push_str(",\"s\":\"t\"");
+        }
+        if !self.args.is_empty() {
+            let args = self
+                .args
+                .iter()
+                .map(|(key, value)| format!("\"{}\":{}", key, json_string(value)))
+                .collect::<Vec<_>>();
+            write!(json, ",\"args\":{{{}}}", args.join(",")).unwrap();
+        }
+        json.push('}');
+        json
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+/// Marks an inner phase of a day as a span named `name`, which ends when the guard is dropped
+///
+/// Nothing is recorded unless a [TraceAnalyzer] was created. Spans are recorded once they end, so
+/// a span that never ends does not show up at all.
+pub fn span(name: &'static str) -> Span {
+    let start = ENABLED.load(Relaxed).then(Instant::now);
+    Span { name, start }
+}
+
+/// Runs `f` inside a [span] named `name` and returns its result
+pub fn in_span<R>(name: &'static str, f: impl FnOnce() -> R) -> R {
+    let _span = span(name);
+    f()
+}
+
+/// A span started by [span], which ends when dropped
+#[must_use = "the span ends when it is dropped"]
+pub struct Span {
+    name: &'static str,
+    /// When the span started, if it is recorded
+    start: Option<Instant>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let epoch = *EPOCH.get_or_init(Instant::now);
+            let mut event = Event::now(self.name, "span", 'X');
+            event.timestamp = micros(start.saturating_duration_since(epoch));
+            event.duration = Some(micros(start.elapsed()));
+            SPANS.lock().unwrap().push((thread::current().id(), event));
+        }
+    }
+}
+
+/// Leaves out the spans of a worker thread that was abandoned because its part timed out
+pub(crate) fn abandon(thread: ThreadId) {
+    ABANDONED.lock().unwrap().push(thread);
+}
+
+/// The events of `spans` that were not recorded on [abandoned][abandon] threads
+fn without_abandoned(spans: Vec<(ThreadId, Event)>) -> Vec<Event> {
+    let abandoned = ABANDONED.lock().unwrap();
+    spans
+        .into_iter()
+        .filter(|(thread, _)| !abandoned.contains(thread))
+        .map(|(_, event)| event)
+        .collect()
+}
+
+/// Writes a trace of the run to a JSON file once all days ran, together with the [span]s days
+/// recorded meanwhile
+///
+/// Days run in parallel show up on their worker threads.
+#[derive(Debug, Default)]
+pub struct TraceAnalyzer {
+    path: PathBuf,
+    events: Vec<Event>,
+    started: bool,
+    forked: bool,
+    /// Whether a year is running, whose end comes after `after_all`
+    in_year: bool,
+    /// The last error of the current step, added to its end event
+    error: Option<String>,
+}
+
+impl TraceAnalyzer {
+    /// Creates an analyzer writing to `path` and starts recording [span]s
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        ENABLED.store(true, Relaxed);
+        EPOCH.get_or_init(Instant::now);
+        TraceAnalyzer {
+            path: path.into(),
+            ..Default::default()
+        }
+    }
+
+    fn push(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    /// Ends a step, with its error if it failed
+    fn end(&mut self, name: String, category: &'static str) {
+        let mut event = Event::now(name, category, 'E');
+        if let Some(error) = self.error.take() {
+            event = event.arg("error", error);
+        }
+        self.push(event);
+    }
+
+    /// The trace of `events`, ordered by time
+    ///
+    /// The sort is stable, so the begin and end events of a step keep their order on ties.
+    fn to_json(mut events: Vec<Event>) -> String {
+        events.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+        let events = events.iter().map(Event::to_json).collect::<Vec<_>>();
+        format!(
+            "{{\"traceEvents\":[\n{}\n],\"displayTimeUnit\":\"ms\"}}\n",
+            events.join(",\n")
+        )
+    }
+
+    fn write(&mut self) {
+        let mut events = std::mem::take(&mut self.events);
+        let spans = std::mem::take(&mut *SPANS.lock().unwrap());
+        events.extend(without_abandoned(spans));
+        if let Err(err) = std::fs::write(&self.path, Self::to_json(events)) {
+            eprintln!("Could not write trace to {}: {}", self.path.display(), err);
+        }
+    }
+}
+
+impl Analyzer for TraceAnalyzer {
+    fn before_all(&mut self) {
+        self.started = true;
+        self.push(Event::now("Run", "run", 'B'));
+    }
+
+    fn after_all(&mut self) {
+        self.push(Event::now("Run", "run", 'E'));
+        if !self.in_year {
+            self.write();
+        }
+    }
+
+    fn before_year(&mut self, year: usize) {
+        self.in_year = true;
+        self.push(Event::now(format!("Year {}", year), "year", 'B'));
+    }
+
+    fn after_year(&mut self, year: usize) {
+        self.push(Event::now(format!("Year {}", year), "year", 'E'));
+        self.in_year = false;
+        if self.started {
+            self.write();
+        }
+    }
+
+    fn before_day(&mut self, day: usize) {
+        self.push(Event::now(format!("Day {}", day), "day", 'B'));
+    }
+
+    fn after_day(&mut self, day: usize) {
+        self.push(Event::now(format!("Day {}", day), "day", 'E'));
+        if !self.started && !self.forked {
+            self.write();
+        }
+    }
+
+    fn before_parse(&mut self, _day: usize) {
+        self.push(Event::now("Parse", "parse", 'B'));
+    }
+
+    fn after_parse(&mut self, _day: usize) {
+        self.end("Parse".to_owned(), "parse");
+    }
+
+    fn before_part(&mut self, _day: usize, part: usize) {
+        self.push(Event::now(format!("Part {}", part), "part", 'B'));
+    }
+
+    fn after_part(&mut self, _day: usize, part: usize, result: Option<&PartResult>) {
+        if let Some(result) = result {
+            let event = Event::now(format!("Part {}", part), "part", 'E');
+            self.push(event.arg("answer", result.answer()));
+        } else {
+            self.end(format!("Part {}", part), "part");
+        }
+    }
+
+    fn on_error(&mut self, _day: usize, _part: Option<usize>, message: &str) {
+        self.error = Some(message.to_owned());
+    }
+
+    fn on_timeout(&mut self, _day: usize, _part: usize, timeout: Duration) {
+        self.error = Some(format!("timed out after {:?}", timeout));
+    }
+
+    fn on_cache_hit(&mut self, _day: usize, part: Option<usize>) {
+        let name = match part {
+            Some(part) => format!("Part {} cached", part),
+            None => "Parsing skipped".to_owned(),
+        };
+        self.push(Event::now(name, "cache", 'i'));
+    }
+}
+
+impl ParallelAnalyzer for TraceAnalyzer {
+    fn fork(&self) -> Self {
+        TraceAnalyzer {
+            path: self.path.clone(),
+            forked: true,
+            ..Default::default()
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.events.extend(other.events);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The events' JSON, with timestamps and threads replaced by their order of appearance
+    fn events(events: &[Event]) -> Vec<String> {
+        events
+            .iter()
+            .enumerate()
+            .map(|(idx, event)| {
+                let mut event = event.clone();
+                event.timestamp = idx as f64;
+                event.thread = 1;
+                event.to_json()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn nest_steps() {
+        let mut analyzer = TraceAnalyzer::new("unused.json");
+        analyzer.before_all();
+        analyzer.before_day(5);
+        analyzer.before_parse(5);
+        analyzer.after_parse(5);
+        analyzer.before_part(5, 1);
+        analyzer.after_part(5, 1, Some(&PartResult::new(&"CMZ")));
+        analyzer.before_part(5, 2);
+        analyzer.on_error(5, Some(2), "Not \"found\"");
+        analyzer.after_part(5, 2, None);
+        analyzer.after_day(5);
+
+        assert_eq!(
+            events(&analyzer.events),
+            vec![
+                r#"{"name":"Run","cat":"run","ph":"B","ts":0.000,"pid":1,"tid":1}"#,
+                r#"{"name":"Day 5","cat":"day","ph":"B","ts":1.000,"pid":1,"tid":1}"#,
+                r#"{"name":"Parse","cat":"parse","ph":"B","ts":2.000,"pid":1,"tid":1}"#,
+                r#"{"name":"Parse","cat":"parse","ph":"E","ts":3.000,"pid":1,"tid":1}"#,
+                r#"{"name":"Part 1","cat":"part","ph":"B","ts":4.000,"pid":1,"tid":1}"#,
+                r#"{"name":"Part 1","cat":"part","ph":"E","ts":5.000,"pid":1,"tid":1,"args":{"answer":"CMZ"}}"#,
+                r#"{"name":"Part 2","cat":"part","ph":"B","ts":6.000,"pid":1,"tid":1}"#,
+                r#"{"name":"Part 2","cat":"part","ph":"E","ts":7.000,"pid":1,"tid":1,"args":{"error":"Not \"found\""}}"#,
+                r#"{"name":"Day 5","cat":"day","ph":"E","ts":8.000,"pid":1,"tid":1}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn record_spans() {
+        let _analyzer = TraceAnalyzer::new("unused.json");
+        let result = in_span("outer", || {
+            let _span = span("inner");
+            42
+        });
+        assert_eq!(result, 42);
+
+        let thread = thread::current().id();
+        let spans = SPANS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(recorded_on, _)| *recorded_on == thread)
+            .map(|(_, event)| event.clone())
+            .collect::<Vec<_>>();
+        let names = spans.iter().map(|event| event.name.as_str());
+        assert_eq!(names.collect::<Vec<_>>(), ["inner", "outer"]);
+        assert!(spans.iter().all(|event| event.phase == 'X'));
+        let (inner, outer) = (&spans[0], &spans[1]);
+        assert!(outer.timestamp <= inner.timestamp);
+        assert!(inner.duration <= outer.duration);
+    }
+
+    #[test]
+    fn drop_abandoned_spans() {
+        let worker = thread::spawn(|| {}).thread().id();
+        let event = Event::now("dfs", "span", 'X');
+        let spans = vec![
+            (worker, event.clone()),
+            (thread::current().id(), event.clone()),
+        ];
+        abandon(worker);
+        assert_eq!(without_abandoned(spans), vec![event]);
+    }
+
+    #[test]
+    fn trace_json() {
+        let events = vec![
+            Event::now("Day 1", "day", 'B'),
+            Event::now("Part 1 cached", "cache", 'i'),
+        ];
+        let json = TraceAnalyzer::to_json(events);
+        assert!(json.starts_with("{\"traceEvents\":[\n{\"name\":\"Day 1\""));
+        assert!(json.contains("\"ph\":\"i\""));
+        assert!(json.contains("\"s\":\"t\""));
+        assert!(json.ends_with("],\"displayTimeUnit\":\"ms\"}\n"));
+    }
+}
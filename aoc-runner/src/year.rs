@@ -0,0 +1,80 @@
+//! A year's puzzles as a registry of days, implemented by `#[derive(AoC)]` with `#[aoc(year = N)]`
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    Analyzer, Cache, DayInfo, Example, ParallelAnalyzer, ParamError, ParamValues, Results,
+};
+
+/// Directory of the input files, relative to the crate's root
+pub const INPUT_DIR: &str = "input";
+
+/// All days of an Advent of Code year
+///
+/// The `run_days*` methods wrap the whole run in [Analyzer::before_year] /
+/// [Analyzer::after_year].
+pub trait Year: Sized {
+    /// The year's number, e.g. `2022`
+    const YEAR: usize;
+
+    /// Creates the year with [Default] values for all days
+    fn new() -> Self;
+
+    /// Lists all days with their metadata and type-erased runners, ordered by day
+    fn days() -> Vec<DayInfo>;
+
+    /// Returns a given day's example input and answers, if it has one
+    fn example(&self, day: usize) -> Option<Example>;
+
+    /// Sets the given parameter values on their days, see [Day::params][crate::Day::params]
+    fn set_params(&mut self, values: &ParamValues) -> Result<(), ParamError>;
+
+    /// Runs one or both parts of the given (day, input) pairs and returns their formatted results
+    ///
+    /// Answers are taken from and added to `cache`, if given.
+    fn run_days<I: AsRef<str>>(
+        &mut self,
+        inputs: &[(usize, I)],
+        part: Option<usize>,
+        analyzer: &mut impl Analyzer,
+        timeout: Option<Duration>,
+        cache: Option<&Cache>,
+    ) -> Results;
+
+    /// Like [Year::run_days], but on up to `threads` threads
+    fn run_days_parallel<I: AsRef<str>, A: ParallelAnalyzer>(
+        &mut self,
+        inputs: &[(usize, I)],
+        part: Option<usize>,
+        analyzer: &mut A,
+        threads: usize,
+        cache: Option<&Cache>,
+    ) -> Results;
+
+    /// Like [Year::run_days], but runs every day `runs` times on fresh instances with the same
+    /// parameters
+    fn run_days_repeated<I: AsRef<str>>(
+        &mut self,
+        inputs: &[(usize, I)],
+        part: Option<usize>,
+        analyzer: &mut impl Analyzer,
+        runs: usize,
+    ) -> Results;
+}
+
+/// The directory of a year's inputs below `root`
+///
+/// That is `input/<year>` if it exists, so several years can keep their inputs side by side, or
+/// `input` otherwise.
+pub fn input_dir(root: impl AsRef<Path>, year: usize) -> PathBuf {
+    let dir = root.as_ref().join(INPUT_DIR);
+    let year_dir = dir.join(year.to_string());
+    if year_dir.is_dir() {
+        year_dir
+    } else {
+        dir
+    }
+}
@@ -1,12 +1,12 @@
 //! Macros for deriving Advent of Code runner utility
-//! 
+//!
 //! # Example
-//! 
+//!
 //! ## Using the default [TimeAnalyzer][aoc_runner::TimeAnalyzer]
-//! ```rust
-//! use aoc_runner::{Day,Analyzer};
+//! ```ignore
+//! use aoc_runner::Analyzer;
 //! use derive_aoc_runner::{AoC, Analyzer};
-//! 
+//!
 //! #[derive(Analyzer)]
 //! #[derive(AoC)]
 //! pub(crate) struct Days(
@@ -18,16 +18,63 @@
 //!   day06::Day06,
 //! );
 //! ```
-//! 
+//!
+//! ## Registering days by attribute
+//! Fields of tuple structs are days 1, 2, … in order. Structs with named fields, or fields that
+//! should not follow that numbering, give their day with `#[aoc(day = N)]`. Days may have gaps.
+//! ```ignore
+//! use aoc_runner::Analyzer;
+//! use derive_aoc_runner::{AoC, Analyzer};
+//!
+//! #[derive(Analyzer, AoC)]
+//! pub(crate) struct Days {
+//!   #[aoc(day = 1)]
+//!   calories: day01::Day01,
+//!   #[aoc(day = 17)]
+//!   pyroclastic_flow: day17::Day17,
+//! }
+//! ```
+//!
+//! ## Multiple years
+//! `#[aoc(year = N)]` on the struct implements [Year][aoc_runner::Year] for it, so a binary can
+//! pick one of several years' registries at runtime. Its `run_days*` methods then also call
+//! [Analyzer::before_year][aoc_runner::Analyzer::before_year] and
+//! [Analyzer::after_year][aoc_runner::Analyzer::after_year].
+//! ```ignore
+//! use aoc_runner::Analyzer;
+//! use derive_aoc_runner::{AoC, Analyzer};
+//!
+//! #[derive(Analyzer, AoC)]
+//! #[aoc(year = 2022)]
+//! pub(crate) struct Days(
+//!   day01::Day01,
+//!   day02::Day02,
+//! );
+//! ```
+//!
+//! ## Running multiple analyzers at once
+//! ```ignore
+//! use aoc_runner::{Analyzer, LogAnalyzer, TimeAnalyzer};
+//! use derive_aoc_runner::{AoC, Analyzer};
+//!
+//! #[derive(Analyzer)]
+//! #[derive(AoC)]
+//! #[analyzer(TimeAnalyzer, LogAnalyzer)]
+//! pub(crate) struct Days(
+//!   day01::Day01,
+//!   day02::Day02,
+//! );
+//! ```
+//!
 //! ## Using a custom Analyzer
-//! ```rust
-//! use aoc_runner::{Day,Analyzer};
+//! ```ignore
+//! use aoc_runner::Analyzer;
 //! use derive_aoc_runner::{AoC, Analyzer};
-//! 
+//!
 //! struct MyAnalyzer;
-//! 
+//!
 //! impl Analyzer for MyAnalyzer {}
-//! 
+//!
 //! #[derive(AoC)]
 //! pub(crate) struct Days(
 //!   day01::Day01,
@@ -37,210 +84,675 @@
 //!   day05::Day05,
 //!   day06::Day06,
 //! );
-//! 
+//!
 //! impl Days {
 //!     fn get_analyzer(&self) -> impl Analyzer {
 //!         MyAnalyzer
 //!     }
 //! }
 //! ```
-//! 
+//!
 //! ## Run puzzles
-//! ```rust
-//! let runner = Days::new(); // creates new runner with Default::default() values for puzzles
-//! runner.run_day( 1 /* day */,               "" /* input */, &mut runner.get_analyzer());
-//! runner.run_part(1 /* day */, 2 /* part */, "" /* input */, &mut runner.get_analyzer());
-//! runner.run_all(["", ""] /* inputs */,                      &mut runner.get_analyzer());
+//! ```ignore
+//! let mut runner = Days::new(); // creates new runner with Default::default() values for puzzles
+//! runner.run_day( 1 /* day */,                     "" /* input */, &mut runner.get_analyzer());
+//! runner.run_part(1 /* day */, Some(2) /* part */, "" /* input */, &mut runner.get_analyzer());
+//! runner.run_all(&["", ""] /* inputs */);
+//! runner.run_all_with(&["", ""] /* inputs */,                      &mut runner.get_analyzer());
+//! runner.run_all_parallel(&["", ""] /* inputs */,                  &mut TimeAnalyzer::new(), 4 /* threads */);
+//! runner.run_all_repeated(&["", ""] /* inputs */,                  &mut runner.get_analyzer(), 10 /* runs */);
 //! ```
-//! 
+//!
+//! ## Regression tests
+//!
+//! `#[derive(AoC)]` also generates a test module with a test per day and part: `dayNN_partP`
+//! solves the part on `input/NN.txt` and compares the result with the answer in
+//! `input/answers.toml` (relative to the crate's root). Parts whose input file or answer is
+//! missing when the tests run pass with a message on why they were skipped. With
+//! `#[aoc(year = N)]`, inputs are read from `input/<N>/` if that directory exists.
+//!
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Data, Fields, FieldsUnnamed};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Attribute, Data, DataStruct, DeriveInput, Fields,
+    Ident, Index, Lit, Member, Meta, MetaNameValue, NestedMeta, Token, Type,
+};
 
 /// Derives multiple utility methods for running Advent of Code puzzles on a struct
-/// 
+///
+/// Every field is a day, which must implement [Day][aoc_runner::Day]. Fields are numbered by
+/// their position, unless they have an `#[aoc(day = N)]` attribute, which fields of structs with
+/// named fields need. Inputs given as a slice (e.g. to `run_all`) are in the order of `days()`.
+/// An `#[aoc(year = N)]` attribute on the struct also implements [Year][aoc_runner::Year].
+///
 /// Derives
 /// - `fn new() -> Self`
 /// - `fn len(&self) -> usize`
 /// - `fn is_empty(&self) -> bool`
-/// - `fn run_day(&self, day: usize, input: &str, analyzer: &mut impl Analyzer)`
-/// - `fn run_part(&self, day: usize, part: usize, input: &str, analyzer: &mut impl Analyzer)`
-/// - `fn run_all<I: AsRef<str>>(&mut self, inputs: &[I])`
-#[proc_macro_derive(AoC)]
+/// - `fn example(&self, day: usize) -> Option<Example>`
+/// - `fn days() -> Vec<DayInfo>`
+/// - `fn set_params(&mut self, values: &ParamValues) -> Result<(), ParamError>`
+/// - `fn run_day(&mut self, day: usize, input: &str, analyzer: &mut impl Analyzer) -> Results`
+/// - `fn run_part(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer) -> Results`
+/// - `fn run_all<I: AsRef<str>>(&mut self, inputs: &[I]) -> Results`
+/// - `fn run_part_with_timeout(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer, timeout: Option<Duration>) -> Results`
+/// - `fn run_part_with_cache(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer, timeout: Option<Duration>, cache: Option<&Cache>) -> Results`
+/// - `fn run_all_with<I: AsRef<str>>(&mut self, inputs: &[I], analyzer: &mut impl Analyzer) -> Results`
+/// - `fn run_all_with_timeout<I: AsRef<str>>(&mut self, inputs: &[I], analyzer: &mut impl Analyzer, timeout: Option<Duration>) -> Results`
+/// - `fn run_days<I: AsRef<str>>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut impl Analyzer, timeout: Option<Duration>, cache: Option<&Cache>) -> Results`
+/// - `fn run_all_parallel<I: AsRef<str>, A: ParallelAnalyzer>(&mut self, inputs: &[I], analyzer: &mut A, threads: usize) -> Results`
+/// - `fn run_days_parallel<I: AsRef<str>, A: ParallelAnalyzer>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut A, threads: usize, cache: Option<&Cache>) -> Results`
+/// - `fn run_part_repeated(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer, runs: usize) -> Results`
+/// - `fn run_all_repeated<I: AsRef<str>>(&mut self, inputs: &[I], analyzer: &mut impl Analyzer, runs: usize) -> Results`
+/// - `fn run_days_repeated<I: AsRef<str>>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut impl Analyzer, runs: usize) -> Results`
+#[proc_macro_derive(AoC, attributes(aoc))]
 pub fn derive_aoc(input: TokenStream) -> TokenStream {
-    let DeriveInput { ident, data, .. } = parse_macro_input!(input);
+    let DeriveInput {
+        ident, attrs, data, ..
+    } = parse_macro_input!(input);
 
-    let usage = format!("#[derive(AoC)] can currently only be applied to structs with unnamend fields.
-
-Also, alls fields must implement aoc_runner::Day + Default.
-
-Example:
-#[derive(Aoc)]
-struct Aoc2022(
-    day01::Day01,
-    day02::Day02,
-);");
-
-    if let Data::Struct(s) = data {
-        if let Fields::Unnamed(FieldsUnnamed { unnamed, .. }) = s.fields {
-            let fields_len = unnamed.len();
-            let fields_ty = unnamed.iter().map(|f| &f.ty);
-            let field_indices = (0..fields_len).map(syn::Index::from);
-
-            let new_impl = {
-                let doc = format!("Creates a new [{}], initialized with default values for all days", ident);
-                quote! {
-                    #[doc = #doc]
-                    pub fn new() -> Self {
-                        Self(#(<#fields_ty>::default(),)*)
-                    }
+    let registered =
+        registered_year(&attrs).and_then(|year| Ok((year, registered_days(&ident, data)?)));
+    match registered {
+        Ok((year, days)) => expand_aoc(&ident, year, &days),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// A field of a `#[derive(AoC)]` struct and the day it is registered for
+struct DayField {
+    day: usize,
+    member: Member,
+    ty: Type,
+}
+
+/// Collects all days of a struct, ordered by day
+///
+/// Fields of tuple structs are numbered by their position, unless they have an
+/// `#[aoc(day = N)]` attribute; fields of structs with named fields need one.
+fn registered_days(ident: &Ident, data: Data) -> syn::Result<Vec<DayField>> {
+    let fields = match data {
+        Data::Struct(s) => s.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "#[derive(AoC)] can only be applied to structs, e.g. `struct Days(day01::Day01, day02::Day02);`",
+            ))
+        }
+    };
+
+    let mut days: Vec<DayField> = Vec::new();
+    for (index, field) in fields.into_iter().enumerate() {
+        let mut day = None;
+        for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("aoc")) {
+            day = Some(aoc_attribute(attr, "day")?);
+        }
+        let member = match field.ident {
+            Some(name) => Member::Named(name),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        let day = match (day, &member) {
+            (Some(day), _) => day,
+            (None, Member::Unnamed(_)) => index + 1,
+            (None, Member::Named(name)) => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "named fields need an `#[aoc(day = N)]` attribute",
+                ))
+            }
+        };
+        if days.iter().any(|other| other.day == day) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!("day {} is registered more than once", day),
+            ));
+        }
+        days.push(DayField {
+            day,
+            member,
+            ty: field.ty,
+        });
+    }
+    days.sort_by_key(|field| field.day);
+    Ok(days)
+}
+
+/// The year of a struct's `#[aoc(year = N)]` attribute, if it has one
+fn registered_year(attrs: &[Attribute]) -> syn::Result<Option<usize>> {
+    let mut year = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("aoc")) {
+        year = Some(aoc_attribute(attr, "year")?);
+    }
+    Ok(year)
+}
+
+/// Parses the number of an `#[aoc(<key> = N)]` attribute
+fn aoc_attribute(attr: &Attribute, key: &str) -> syn::Result<usize> {
+    let error = || {
+        syn::Error::new_spanned(
+            attr,
+            format!("expected `#[aoc({} = N)]` with a number N >= 1", key),
+        )
+    };
+    let Meta::List(list) = attr.parse_meta()? else {
+        return Err(error());
+    };
+    match list.nested.iter().collect::<Vec<_>>()[..] {
+        [NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+            path,
+            lit: Lit::Int(day),
+            ..
+        }))] if path.is_ident(key) => match day.base10_parse::<usize>()? {
+            0 => Err(syn::Error::new_spanned(day, format!("{}s start at 1", key))),
+            day => Ok(day),
+        },
+        _ => Err(error()),
+    }
+}
+
+fn expand_aoc(ident: &Ident, year: Option<usize>, days: &[DayField]) -> TokenStream {
+    let fields_len = days.len();
+    let fields_ty = days.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let members = days.iter().map(|field| &field.member).collect::<Vec<_>>();
+    let day_numbers = days.iter().map(|field| field.day).collect::<Vec<_>>();
+    let valid_days = day_numbers
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let (before_year, after_year) = match year {
+        Some(year) => (
+            quote! { analyzer.before_year(#year); },
+            quote! { analyzer.after_year(#year); },
+        ),
+        None => (quote! {}, quote! {}),
+    };
+
+    let new_impl = {
+        let doc = format!(
+            "Creates a new [{}], initialized with default values for all days",
+            ident
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn new() -> Self {
+                Self { #(#members: <#fields_ty>::default(),)* }
+            }
+        }
+    };
+
+    let len_impl = {
+        let doc = "Return the number of available days";
+        quote! {
+            #[doc = #doc]
+            pub fn len(&self) -> usize {
+                #fields_len
+            }
+        }
+    };
+
+    let is_empty_impl = {
+        let doc = "Return true if the container hosts no days";
+        quote! {
+            #[doc = #doc]
+            pub fn is_empty(&self) -> bool {
+                #fields_len == 0
+            }
+        }
+    };
+
+    let example_impl = {
+        let doc = "Returns a given day's example input and answers, if it has one";
+        quote! {
+            #[doc = #doc]
+            pub fn example(&self, day: usize) -> Option<aoc_runner::Example> {
+                match day {
+                    #( #day_numbers => <#fields_ty as aoc_runner::Day>::EXAMPLE, )*
+                    _ => None,
                 }
-            };
-
-            let len_impl = {
-                let doc = "Return the number of available days";
-                quote! {
-                    #[doc = #doc]
-                    pub fn len(&self) -> usize {
-                        #fields_len
-                    }
+            }
+        }
+    };
+
+    let days_impl = {
+        let doc = "Lists all days with their metadata and type-erased runners";
+        quote! {
+            #[doc = #doc]
+            pub fn days() -> Vec<aoc_runner::DayInfo> {
+                vec![#( aoc_runner::DayInfo::of::<#fields_ty>(#day_numbers), )*]
+            }
+        }
+    };
+
+    let set_params_impl = {
+        let doc = "Sets the given parameter values on their days, see [aoc_runner::Day::params]";
+        quote! {
+            #[doc = #doc]
+            pub fn set_params(&mut self, values: &aoc_runner::ParamValues) -> Result<(), aoc_runner::ParamError> {
+                #( values.apply(&mut self.#members, #day_numbers)?; )*
+                Ok(())
+            }
+        }
+    };
+
+    let run_day_impl = {
+        let doc = "Runs both parts of a given day";
+        quote! {
+            #[doc = #doc]
+            pub fn run_day(&mut self, day: usize, input: &str, analyzer: &mut impl Analyzer) -> aoc_runner::Results {
+                self.run_part(day, None, input, analyzer)
+            }
+        }
+    };
+
+    let run_part_impl = {
+        let doc = "Runs one or both parts of a given day and returns their formatted results\n\nParse errors and panics are reported to the analyzer instead of aborting, see [aoc_runner::run_day]";
+        let doc_timeout = "Like `run_part`, but stops waiting for a part that runs longer than `timeout`, see [aoc_runner::runner::run_day_with_timeout]";
+        let doc_cache = "Like `run_part_with_timeout`, but takes answers from and adds them to `cache`, see [aoc_runner::runner::run_day_with_cache]";
+        quote! {
+            #[doc = #doc]
+            pub fn run_part(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer) -> aoc_runner::Results {
+                self.run_part_with_timeout(day, part, input, analyzer, None)
+            }
+
+            #[doc = #doc_timeout]
+            pub fn run_part_with_timeout(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer, timeout: Option<std::time::Duration>) -> aoc_runner::Results {
+                self.run_part_with_cache(day, part, input, analyzer, timeout, None)
+            }
+
+            #[doc = #doc_cache]
+            pub fn run_part_with_cache(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer, timeout: Option<std::time::Duration>, cache: Option<&aoc_runner::Cache>) -> aoc_runner::Results {
+                match day {
+                    #( #day_numbers => aoc_runner::runner::run_day_with_cache(&mut self.#members, day, part, input, analyzer, timeout, cache), )*
+                    _ => panic!("Invalid day: {}. Valid days are {}", day, #valid_days)
                 }
-            };
-
-            let is_empty_impl = {
-                let doc = "Return true if the container hosts no days";
-                quote! {
-                    #[doc = #doc]
-                    pub fn is_empty(&self) -> bool {
-                        #fields_len == 0
-                    }
+            }
+        }
+    };
+
+    let run_all_impl = {
+        let doc = "Runs both parts of all available days and returns their formatted results";
+        quote! {
+            #[doc = #doc]
+            pub fn run_all<I: AsRef<str>>(&mut self, inputs: &[I]) -> aoc_runner::Results {
+                let mut analyzer = self.get_analyzer();
+                self.run_all_with(inputs, &mut analyzer)
+            }
+        }
+    };
+
+    let run_all_parallel_impl = {
+        let doc = "Runs both parts of all available days on up to `threads` threads and returns their formatted results\n\nEvery day is analyzed by an analyzer forked from `analyzer`, which are merged back once all days are done";
+        let doc_days = "Like `run_all_parallel`, but runs one or both parts of the given (day, input) pairs only";
+        let field_names = day_numbers
+            .iter()
+            .map(|day| format_ident!("day{}", day))
+            .collect::<Vec<_>>();
+        quote! {
+            #[doc = #doc]
+            pub fn run_all_parallel<I: AsRef<str>, A: aoc_runner::ParallelAnalyzer>(&mut self, inputs: &[I], analyzer: &mut A, threads: usize) -> aoc_runner::Results {
+                assert_eq!(inputs.len(), self.len());
+
+                let inputs = [#(#day_numbers),*].into_iter().zip(inputs.iter().map(|input| input.as_ref())).collect::<Vec<_>>();
+                self.run_days_parallel(&inputs, None, analyzer, threads, None)
+            }
+
+            #[doc = #doc_days]
+            pub fn run_days_parallel<I: AsRef<str>, A: aoc_runner::ParallelAnalyzer>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut A, threads: usize, cache: Option<&aoc_runner::Cache>) -> aoc_runner::Results {
+                if let Some((day, _)) = inputs.iter().find(|(day, _)| ![#(#day_numbers),*].contains(day)) {
+                    panic!("Invalid day: {}. Valid days are {}", day, #valid_days);
                 }
-            };
-
-            let run_day_impl = {
-                let doc = "Runs both parts of a given day";
-                quote! {
-                    #[doc = #doc]
-                    pub fn run_day(&mut self, day: usize, input: &str, analyzer: &mut impl Analyzer) {
-                        self.run_part(day, None, input, analyzer);
+
+                let Self { #(#members: #field_names,)* } = self;
+                let mut jobs = Vec::new();
+                #(
+                    if let Some((_, input)) = inputs.iter().find(|(day, _)| *day == #day_numbers) {
+                        jobs.push(aoc_runner::parallel::Job::new(#field_names, #day_numbers, part, input.as_ref(), cache));
                     }
+                )*
+                #before_year
+                let results = aoc_runner::parallel::run_all(jobs, analyzer, threads);
+                #after_year
+                results
+            }
+        }
+    };
+
+    let run_repeated_impl = {
+        let doc = "Runs one or both parts of a given day `runs` times on fresh instances (with the day's parameters) and returns the results of the first run\n\nThe whole run is wrapped in `before_all` / `after_all`, so the analyzer reports once";
+        let doc_all = "Runs both parts of all available days `runs` times on fresh instances and returns the results of the first runs";
+        let doc_days = "Like `run_all_repeated`, but runs one or both parts of the given (day, input) pairs only";
+        quote! {
+            #[doc = #doc]
+            pub fn run_part_repeated(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer, runs: usize) -> aoc_runner::Results {
+                self.run_days_repeated(&[(day, input)], part, analyzer, runs)
+            }
+
+            #[doc = #doc_all]
+            pub fn run_all_repeated<I: AsRef<str>>(&mut self, inputs: &[I], analyzer: &mut impl Analyzer, runs: usize) -> aoc_runner::Results {
+                assert_eq!(inputs.len(), self.len());
+
+                let inputs = [#(#day_numbers),*].into_iter().zip(inputs.iter().map(|input| input.as_ref())).collect::<Vec<_>>();
+                self.run_days_repeated(&inputs, None, analyzer, runs)
+            }
+
+            #[doc = #doc_days]
+            pub fn run_days_repeated<I: AsRef<str>>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut impl Analyzer, runs: usize) -> aoc_runner::Results {
+                let mut results = aoc_runner::Results::new();
+
+                #before_year
+                analyzer.before_all();
+                for (day, input) in inputs {
+                    results.append(&mut self.run_repeated(*day, part, input.as_ref(), analyzer, runs));
                 }
-            };
-
-            let run_part_impl = {
-                let doc = "Runs one or both parts of a given day";
-                quote! {
-                    #[doc = #doc]
-                    pub fn run_part(&mut self, day: usize, part: Option<usize>, input: &str, mut analyzer: &mut impl Analyzer) {
-                        match day - 1 {
-                            #( #field_indices => {
-                                println!("Day {}", day);
-
-                                analyzer.before_day(day);
-                                analyzer.before_parse(day);
-                                self.#field_indices.parse(input);
-                                analyzer.after_parse(day);
-                                match part {
-                                    Some(1) => {
-                                        analyzer.before_part(day, 1);
-                                        let result = self.#field_indices.part1();
-                                        analyzer.after_part(day, 1);
-                                        self.#field_indices.print_part1(result);
-                                    }
-                                    Some(2) => {
-                                        analyzer.before_part(day, 2);
-                                        let result = self.#field_indices.part2();
-                                        analyzer.after_part(day, 2);
-                                        self.#field_indices.print_part2(result);
-                                    }
-                                    None => {
-                                        analyzer.before_part(day, 1);
-                                        let result = self.#field_indices.part1();
-                                        analyzer.after_part(day, 1);
-                                        self.#field_indices.print_part1(result);
-
-                                        analyzer.before_part(day, 2);
-                                        let result = self.#field_indices.part2();
-                                        analyzer.after_part(day, 2);
-                                        self.#field_indices.print_part2(result);
-                                    }
-                                    Some(part) => panic!("Invalid part: {}. Valid parts are: 1,2", part)
-                                }
-                                analyzer.after_day(day);
-                            } )*
-                            _ => panic!("Invalid day: {}. Valid days are 1..{}", day, #fields_len)
-                        }
-                    }
+                analyzer.after_all();
+                #after_year
+                results
+            }
+
+            fn run_repeated(&mut self, day: usize, part: Option<usize>, input: &str, analyzer: &mut impl Analyzer, runs: usize) -> aoc_runner::Results {
+                match day {
+                    #( #day_numbers => aoc_runner::runner::run_day_repeated(&mut self.#members, day, part, input, analyzer, runs), )*
+                    _ => panic!("Invalid day: {}. Valid days are {}", day, #valid_days)
                 }
-            };
-
-            let run_all_impl = {
-                let doc = "Runs both parts of all available days";
-                quote! {
-                    #[doc = #doc]
-                    pub fn run_all<I: AsRef<str>>(&mut self, inputs: &[I]) {
-                        assert_eq!(inputs.len(), self.len());
-
-                        let mut analyzer = self.get_analyzer();
-
-                        analyzer.before_all();
-                        for i in 1..=self.len() {
-                            let input = inputs[i - 1].as_ref();
-                            self.run_day(i, input, &mut analyzer);
-                        }
-                        analyzer.after_all();
-                    }
+            }
+        }
+    };
+
+    let run_all_with_impl = {
+        let doc = "Runs both parts of all available days with a given analyzer and returns their formatted results";
+        let doc_timeout =
+            "Like `run_all_with`, but stops waiting for a part that runs longer than `timeout`";
+        let doc_days = "Runs one or both parts of the given (day, input) pairs with a given analyzer and returns their formatted results";
+        quote! {
+            #[doc = #doc]
+            pub fn run_all_with<I: AsRef<str>>(&mut self, inputs: &[I], analyzer: &mut impl Analyzer) -> aoc_runner::Results {
+                self.run_all_with_timeout(inputs, analyzer, None)
+            }
+
+            #[doc = #doc_timeout]
+            pub fn run_all_with_timeout<I: AsRef<str>>(&mut self, inputs: &[I], analyzer: &mut impl Analyzer, timeout: Option<std::time::Duration>) -> aoc_runner::Results {
+                assert_eq!(inputs.len(), self.len());
+
+                let inputs = [#(#day_numbers),*].into_iter().zip(inputs.iter().map(|input| input.as_ref())).collect::<Vec<_>>();
+                self.run_days(&inputs, None, analyzer, timeout, None)
+            }
+
+            #[doc = #doc_days]
+            pub fn run_days<I: AsRef<str>>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut impl Analyzer, timeout: Option<std::time::Duration>, cache: Option<&aoc_runner::Cache>) -> aoc_runner::Results {
+                let mut results = aoc_runner::Results::new();
+
+                #before_year
+                analyzer.before_all();
+                for (day, input) in inputs {
+                    results.append(&mut self.run_part_with_cache(*day, part, input.as_ref(), analyzer, timeout, cache));
+                }
+                analyzer.after_all();
+                #after_year
+                results
+            }
+        }
+    };
+
+    let year_impl = year.map(|year| {
+        quote! {
+            impl aoc_runner::Year for #ident {
+                const YEAR: usize = #year;
+
+                fn new() -> Self {
+                    #ident::new()
+                }
+
+                fn days() -> Vec<aoc_runner::DayInfo> {
+                    #ident::days()
                 }
-            };
-
-            let output = quote! {
-                impl #ident {
-                    #new_impl
-                    #len_impl
-                    #is_empty_impl
-                    #run_day_impl
-                    #run_part_impl
-                    #run_all_impl
+
+                fn example(&self, day: usize) -> Option<aoc_runner::Example> {
+                    #ident::example(self, day)
+                }
+
+                fn set_params(&mut self, values: &aoc_runner::ParamValues) -> Result<(), aoc_runner::ParamError> {
+                    #ident::set_params(self, values)
                 }
-            };
 
-            // panic!("{}", output.to_string());
-            output.into()
-        } else {
-            panic!("{}", usage)
+                fn run_days<I: AsRef<str>>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut impl Analyzer, timeout: Option<std::time::Duration>, cache: Option<&aoc_runner::Cache>) -> aoc_runner::Results {
+                    #ident::run_days(self, inputs, part, analyzer, timeout, cache)
+                }
+
+                fn run_days_parallel<I: AsRef<str>, A: aoc_runner::ParallelAnalyzer>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut A, threads: usize, cache: Option<&aoc_runner::Cache>) -> aoc_runner::Results {
+                    #ident::run_days_parallel(self, inputs, part, analyzer, threads, cache)
+                }
+
+                fn run_days_repeated<I: AsRef<str>>(&mut self, inputs: &[(usize, I)], part: Option<usize>, analyzer: &mut impl Analyzer, runs: usize) -> aoc_runner::Results {
+                    #ident::run_days_repeated(self, inputs, part, analyzer, runs)
+                }
+            }
         }
-    } else {
-        panic!("{}", usage)
-    }
+    });
+
+    let tests = regression_tests(ident, year, days);
+
+    let output = quote! {
+        impl #ident {
+            #new_impl
+            #len_impl
+            #is_empty_impl
+            #example_impl
+            #days_impl
+            #set_params_impl
+            #run_day_impl
+            #run_part_impl
+            #run_all_impl
+            #run_all_with_impl
+            #run_all_parallel_impl
+            #run_repeated_impl
+        }
+
+        #year_impl
+
+        #tests
+    };
+
+    output.into()
+}
+
+/// Generates a test per day and part that checks the part's answer on the input file
+///
+/// Inputs are looked up when the tests run, so parts without input or answer are skipped then
+/// and new inputs are picked up without rebuilding.
+fn regression_tests(ident: &Ident, year: Option<usize>, days: &[DayField]) -> TokenStream2 {
+    let module = format_ident!("{}_regression", ident.to_string().to_lowercase());
+    let input_dir = match year {
+        Some(year) => quote! { aoc_runner::year::input_dir(env!("CARGO_MANIFEST_DIR"), #year) },
+        None => quote! {
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(aoc_runner::year::INPUT_DIR)
+        },
+    };
+    let tests = days.iter().flat_map(|field| {
+        let ty = &field.ty;
+        let day = field.day;
+        let input_dir = &input_dir;
+        [1usize, 2].into_iter().map(move |part| {
+            let test = format_ident!("day{:0>2}_part{}", day, part);
+            quote! {
+                #[test]
+                fn #test() {
+                    aoc_runner::testing::verify_input::<#ty>(#day, #part, #input_dir);
+                }
+            }
+        })
+    });
+    quote! {
+        /// Regression tests for every day's parts on its input
+        #[cfg(test)]
+        mod #module {
+            use super::*;
 
+            #(#tests)*
+        }
+    }
 }
 
 /// Derives a method for this struct that returns an [Analyzer][aoc_runner::Analyzer] instance
-/// 
+///
+/// By default a [TimeAnalyzer][aoc_runner::TimeAnalyzer] is used. Other analyzers can be given
+/// with the `analyzer` attribute, e.g. `#[analyzer(TimeAnalyzer, LogAnalyzer)]`. All of them
+/// must implement [Default] and are run together, in order.
+///
 /// Derives
-/// - `fn get_analyzer(&self) -> impl Analyzer`
-#[proc_macro_derive(Analyzer)]
+/// - `fn get_analyzer(&self) -> A`
+#[proc_macro_derive(Analyzer, attributes(analyzer))]
 pub fn derive_get_analyzer(input: TokenStream) -> TokenStream {
-    let DeriveInput { ident, .. } = parse_macro_input!(input);
+    let DeriveInput { ident, attrs, .. } = parse_macro_input!(input);
+
+    let mut analyzers = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("analyzer")) {
+        match attr.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated) {
+            Ok(types) => analyzers.extend(types),
+            Err(err) => return err.to_compile_error().into(),
+        }
+    }
+
+    let analyzer_ty = match &analyzers[..] {
+        [] => quote! { aoc_runner::TimeAnalyzer },
+        [analyzer] => quote! { #analyzer },
+        analyzers => quote! { ( #(#analyzers,)* ) },
+    };
 
     let get_analyzer_impl = {
         let doc = "Creates a new analyzer to use during execution";
         quote! {
             #[doc = #doc]
-            pub fn get_analyzer(&self) -> impl Analyzer {
-                TimeAnalyzer::new()
+            pub fn get_analyzer(&self) -> #analyzer_ty {
+                Default::default()
             }
         }
     };
 
     let output = quote! {
-        use aoc_runner::{TimeAnalyzer};
         impl #ident {
             #get_analyzer_impl
         }
     };
 
     output.into()
+}
+
+/// Implements [Params][aoc_runner::Params] for a struct with named fields
+///
+/// Every field is a parameter named like the field, which is set by [FromStr][std::str::FromStr]
+/// and listed by [ToString].
+///
+/// ```rust
+/// use derive_aoc_runner::Params;
+///
+/// #[derive(Params)]
+/// struct Params {
+///     row: usize,
+///     area: i32,
+/// }
+/// ```
+#[proc_macro_derive(Params)]
+pub fn derive_params(input: TokenStream) -> TokenStream {
+    let DeriveInput { ident, data, .. } = parse_macro_input!(input);
 
+    let fields = match data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => fields.named,
+        _ => {
+            return syn::Error::new_spanned(
+                ident,
+                "#[derive(Params)] can only be applied to structs with named fields",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let names = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect::<Vec<_>>();
+    let keys = names
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let known = format!("one of {}", keys.join(", "));
+
+    let output = quote! {
+        impl aoc_runner::Params for #ident {
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    #( #keys => {
+                        self.#names = value.parse::<#types>().map_err(|_| stringify!(#types).to_owned())?;
+                        Ok(())
+                    } )*
+                    _ => Err(#known.to_owned()),
+                }
+            }
+
+            fn values(&self) -> Vec<(&'static str, String)> {
+                vec![#( (#keys, self.#names.to_string()), )*]
+            }
+        }
+    };
+
+    output.into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use syn::parse_quote;
+
+    fn days(input: DeriveInput) -> syn::Result<Vec<usize>> {
+        let days = registered_days(&input.ident, input.data)?;
+        Ok(days.iter().map(|field| field.day).collect())
+    }
+
+    #[test]
+    fn register_days() {
+        let tuple = parse_quote! { struct Days(day01::Day01, #[aoc(day = 5)] day05::Day05); };
+        assert_eq!(days(tuple).unwrap(), vec![1, 5]);
+
+        let named = parse_quote! {
+            struct Sparse {
+                #[aoc(day = 17)]
+                pyroclastic_flow: day17::Day17,
+                #[aoc(day = 2)]
+                rock_paper_scissors: day02::Day02,
+            }
+        };
+        assert_eq!(days(named).unwrap(), vec![2, 17]);
+
+        let unnumbered = parse_quote! { struct Days { day01: day01::Day01 } };
+        assert!(days(unnumbered).is_err());
+        let twice = parse_quote! { struct Days(day01::Day01, #[aoc(day = 1)] day01::Day01); };
+        assert!(days(twice).is_err());
+    }
+
+    #[test]
+    fn regression_tests_for_every_part() {
+        let input: DeriveInput = parse_quote! {
+            struct Sparse {
+                #[aoc(day = 17)]
+                pyroclastic_flow: day17::Day17,
+                #[aoc(day = 2)]
+                rock_paper_scissors: day02::Day02,
+            }
+        };
+        let days = registered_days(&input.ident, input.data).unwrap();
+        let tests = regression_tests(&input.ident, Some(2022), &days).to_string();
+        assert!(tests.contains("mod sparse_regression"));
+        for test in ["day02_part1", "day02_part2", "day17_part1", "day17_part2"] {
+            assert!(tests.contains(&format!("fn {} ()", test)), "{}", test);
+        }
+        // Whether inputs exist is only decided when the tests run
+        assert!(!tests.contains("ignore"));
+        assert!(tests.contains("verify_input :: < day17 :: Day17 > (17usize , 1usize"));
+    }
 }
@@ -0,0 +1,693 @@
+//! Command line interface of the runner
+//!
+//! `aoc2022 [command] [days] [part] [options]`, where `days` selects days by number or range,
+//! e.g. `1-5,12,20-`.
+//!
+//! Inputs, answers, parameters and baselines default to the selected year's input directory, see
+//! [aoc_runner::year::input_dir].
+
+use std::{fmt::Display, path::PathBuf, time::Duration};
+
+use aoc_runner::{
+    input, parallel::default_threads, testing::ANSWERS_FILE, year::input_dir, ExportFormat, Format,
+    ParamValues,
+};
+
+pub const BASELINE_FILE: &str = "baseline.csv";
+pub const PARAMS_FILE: &str = "params.toml";
+
+/// Directory of the answer caches, which hold a `<year>.csv` file per year
+pub const CACHE_DIR: &str = "target/aoc-cache";
+
+/// Percentage a step may get slower than its baseline, if not given by `--threshold`
+pub const THRESHOLD: f64 = 10.0;
+
+/// Number of runs per day of the `bench` command, if not given by `--runs`
+pub const BENCH_RUNS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Run the selected days
+    Run,
+    /// Run the selected days and verify their results
+    Check,
+    /// List the selected days and their inputs
+    List,
+    /// Run the selected days repeatedly and show timing statistics
+    Bench,
+    /// Create a new day from the template
+    NewDay,
+}
+
+/// Where to read puzzle inputs from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A directory containing `NN.txt` files or a single input file
+    Path(PathBuf),
+    Stdin,
+    /// The example inputs from the puzzles' descriptions
+    Example,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub year: usize,
+    pub command: Command,
+    pub days: Vec<usize>,
+    pub part: Option<usize>,
+    pub input: Input,
+    pub answers: PathBuf,
+    /// Parameter values given by `--param`, which override the ones of `params_file`
+    pub params: ParamValues,
+    pub params_file: PathBuf,
+    /// Whether `params_file` was given, so it must exist
+    pub params_given: bool,
+    pub exports: Vec<(PathBuf, ExportFormat)>,
+    pub format: Format,
+    pub threads: Option<usize>,
+    pub log: bool,
+    /// The file to write a trace of the run to, see [aoc_runner::trace]
+    pub trace: Option<PathBuf>,
+    /// Whether to count allocations, see [aoc_runner::alloc]
+    pub allocations: bool,
+    /// The answer cache file, if `--cache` is given (and not overridden by a later `--no-cache`)
+    pub cache: Option<PathBuf>,
+    pub runs: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub baseline: Option<PathBuf>,
+    pub threshold: f64,
+    pub help: bool,
+}
+
+/// An invalid command line or an input that could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliError(String);
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+macro_rules! cli_error {
+    ($($arg:tt)*) => {
+        CliError(format!($($arg)*))
+    };
+}
+
+impl Options {
+    /// Parses the command line arguments (without the binary's name) for the `available` days of
+    /// a `year`, which must have been selected by [parse_year] before
+    pub fn parse(args: &[String], year: usize, available: &[usize]) -> Result<Self, CliError> {
+        let dir = input_dir(".", year);
+        let mut options = Options {
+            year,
+            command: Command::Run,
+            days: available.to_vec(),
+            part: None,
+            input: Input::Path(dir.clone()),
+            answers: dir.join(ANSWERS_FILE),
+            params: ParamValues::new(),
+            params_file: dir.join(PARAMS_FILE),
+            params_given: false,
+            exports: vec![],
+            format: Format::Human,
+            threads: None,
+            log: false,
+            trace: None,
+            allocations: false,
+            cache: None,
+            runs: None,
+            timeout: None,
+            baseline: None,
+            threshold: THRESHOLD,
+            help: false,
+        };
+        let mut positional = vec![];
+        let mut input_given = false;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "-h" || arg == "--help" {
+                options.help = true;
+                continue;
+            }
+            let Some(option) = arg.strip_prefix("--") else {
+                positional.push(arg.as_str());
+                continue;
+            };
+
+            let (name, inline) = match option.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_owned())),
+                None => (option, None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next().cloned())
+                    .ok_or_else(|| cli_error!("option '--{}' expects a value", name))
+            };
+            match name {
+                "year" => {
+                    value()?;
+                }
+                "input" => {
+                    if options.input == Input::Example {
+                        return Err(cli_error!("'--input' can not be combined with '--example'"));
+                    }
+                    let input = value()?;
+                    input_given = true;
+                    options.input = match input.as_str() {
+                        "-" => Input::Stdin,
+                        _ => Input::Path(PathBuf::from(input)),
+                    };
+                }
+                "example" if inline.is_none() => {
+                    if input_given {
+                        return Err(cli_error!("'--example' can not be combined with '--input'"));
+                    }
+                    options.input = Input::Example;
+                }
+                "answers" => options.answers = PathBuf::from(value()?),
+                "param" => {
+                    let param = value()?;
+                    match ParamValues::parse_arg(&param) {
+                        Some((day, key, value)) if available.contains(&day) => {
+                            options.params.insert(day, key, value)
+                        }
+                        Some((day, _, _)) => {
+                            return Err(cli_error!(
+                                "day {} does not exist, available days are {}",
+                                day,
+                                format_days(available)
+                            ))
+                        }
+                        None => {
+                            return Err(cli_error!(
+                                "invalid parameter '{}': expected <day>.<name>=<value>",
+                                param
+                            ))
+                        }
+                    }
+                }
+                "params" => {
+                    options.params_file = PathBuf::from(value()?);
+                    options.params_given = true;
+                }
+                "check" => {
+                    options.command = Command::Check;
+                    if let Some(answers) = &inline {
+                        options.answers = PathBuf::from(answers);
+                    }
+                }
+                "export" => {
+                    let path = PathBuf::from(value()?);
+                    let format = ExportFormat::from_path(&path).ok_or_else(|| {
+                        cli_error!(
+                            "can not export timings to '{}': expected a .json, .csv or .md file",
+                            path.display()
+                        )
+                    })?;
+                    options.exports.push((path, format));
+                }
+                "format" => {
+                    let format = value()?;
+                    options.format = Format::from_name(&format).ok_or_else(|| {
+                        cli_error!("invalid format '{}': expected human, plain or json", format)
+                    })?;
+                }
+                "save-baseline" => {
+                    let path = inline.map_or_else(|| dir.join(BASELINE_FILE), PathBuf::from);
+                    options.exports.push((path, ExportFormat::Csv));
+                }
+                "baseline" => {
+                    options.baseline =
+                        Some(inline.map_or_else(|| dir.join(BASELINE_FILE), PathBuf::from))
+                }
+                "threshold" => {
+                    let threshold = value()?;
+                    match threshold.trim_end_matches('%').parse::<f64>() {
+                        Ok(percent) if percent >= 0.0 && percent.is_finite() => {
+                            options.threshold = percent
+                        }
+                        _ => {
+                            return Err(cli_error!(
+                                "invalid threshold '{}': expected a percentage",
+                                threshold
+                            ))
+                        }
+                    }
+                }
+                "parallel" => {
+                    options.threads = Some(match &inline {
+                        Some(threads) => parse_count(threads, "number of threads")?,
+                        None => default_threads(),
+                    });
+                }
+                "log" if inline.is_none() => options.log = true,
+                "cache" if inline.is_none() => {
+                    options.cache = Some(PathBuf::from(CACHE_DIR).join(format!("{}.csv", year)))
+                }
+                "no-cache" if inline.is_none() => options.cache = None,
+                "allocations" if inline.is_none() => options.allocations = true,
+                "trace" => options.trace = Some(PathBuf::from(value()?)),
+                "runs" => options.runs = Some(parse_count(&value()?, "number of runs")?),
+                "timeout" => {
+                    let timeout = value()?;
+                    match timeout.parse::<f64>() {
+                        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => {
+                            options.timeout = Some(Duration::from_secs_f64(seconds))
+                        }
+                        _ => {
+                            return Err(cli_error!(
+                                "invalid timeout '{}': expected seconds",
+                                timeout
+                            ))
+                        }
+                    }
+                }
+                _ => return Err(cli_error!("unknown option '{}'", arg)),
+            }
+        }
+
+        let mut positional = positional.into_iter().peekable();
+        if let Some(command) = positional.peek().and_then(|arg| parse_command(arg)) {
+            options.command = command;
+            positional.next();
+        }
+        if options.command == Command::NewDay {
+            let day = positional
+                .next()
+                .ok_or_else(|| cli_error!("'new-day' expects a day"))?;
+            match day.parse() {
+                Ok(day) if (1..=25).contains(&day) => options.days = vec![day],
+                _ => return Err(cli_error!("invalid day '{}': expected 1-25", day)),
+            }
+        } else if let Some(days) = positional.next() {
+            options.days = parse_days(days, available)?;
+        }
+        if let Some(part) = positional.next() {
+            options.part = match part {
+                "1" => Some(1),
+                "2" => Some(2),
+                _ => return Err(cli_error!("invalid part '{}': expected 1 or 2", part)),
+            };
+        }
+        if let Some(arg) = positional.next() {
+            return Err(cli_error!("unexpected argument '{}'", arg));
+        }
+
+        if options.command == Command::Bench {
+            options.runs = options.runs.or(Some(BENCH_RUNS));
+        }
+        if options.timeout.is_some() && (options.threads.is_some() || options.runs.is_some()) {
+            return Err(cli_error!(
+                "'--timeout' can not be combined with '--parallel', '--runs' or 'bench'"
+            ));
+        }
+        if options.threads.is_some() && options.runs.is_some() {
+            return Err(cli_error!(
+                "'--parallel' can not be combined with '--runs' or 'bench'"
+            ));
+        }
+        if options.allocations && (options.threads.is_some() || options.timeout.is_some()) {
+            // Allocations are counted process-wide, so days running at once or abandoned after a
+            // timeout would mix them up
+            return Err(cli_error!(
+                "'--allocations' can not be combined with '--parallel' or '--timeout'"
+            ));
+        }
+        // Cached parts are not timed, so nothing that needs their timings may use the cache
+        let timed = !options.exports.is_empty()
+            || options.baseline.is_some()
+            || options.allocations
+            || options.runs.is_some();
+        if options.cache.is_some() && timed {
+            return Err(cli_error!(
+                "'--cache' can not be combined with '--export', '--save-baseline', '--baseline', \
+                 '--allocations', '--runs' or 'bench'"
+            ));
+        }
+
+        Ok(options)
+    }
+
+    /// The file to read a day's input from, if it is not read from stdin or an example
+    pub fn input_file(&self, day: usize) -> Option<PathBuf> {
+        match &self.input {
+            Input::Path(path) if path.is_file() => Some(path.clone()),
+            Input::Path(path) => Some(path.join(format!("{:0>2}.txt", day))),
+            Input::Stdin | Input::Example => None,
+        }
+    }
+
+    /// Reads the inputs of all selected days
+    ///
+    /// A single input file or stdin can only be used if exactly one day is selected. Examples are
+    /// not read from anywhere, so no inputs are returned for them.
+    pub fn read_inputs(&self) -> Result<Vec<(usize, String)>, CliError> {
+        let single_input = match &self.input {
+            Input::Path(path) => path.is_file(),
+            Input::Stdin => true,
+            Input::Example => return Ok(vec![]),
+        };
+        if single_input && self.days.len() != 1 {
+            return Err(cli_error!(
+                "a single input file or stdin requires a single day to be selected, but {} are",
+                self.days.len()
+            ));
+        }
+
+        self.days
+            .iter()
+            .map(|&day| {
+                let input = match self.input_file(day) {
+                    Some(path) => input::read(&path).map_err(|err| {
+                        cli_error!("could not read input file '{}': {}", path.display(), err)
+                    })?,
+                    None => input::read_stdin()
+                        .map_err(|err| cli_error!("could not read input from stdin: {}", err))?,
+                };
+                Ok((day, input))
+            })
+            .collect()
+    }
+
+    /// All selected (day, part) tuples
+    pub fn parts(&self) -> Vec<(usize, usize)> {
+        self.days
+            .iter()
+            .flat_map(|&day| match self.part {
+                Some(part) => vec![(day, part)],
+                None => vec![(day, 1), (day, 2)],
+            })
+            .collect()
+    }
+}
+
+/// Selects one of the registered `years` by `--year`, defaulting to the latest one
+pub fn parse_year(args: &[String], years: &[usize]) -> Result<usize, CliError> {
+    let mut selected = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--year" {
+            let year = args
+                .next()
+                .ok_or_else(|| cli_error!("option '--year' expects a value"))?;
+            selected = Some(year.as_str());
+        } else if let Some(year) = arg.strip_prefix("--year=") {
+            selected = Some(year);
+        }
+    }
+
+    let available = || {
+        years
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match selected {
+        None => years
+            .iter()
+            .max()
+            .copied()
+            .ok_or_else(|| cli_error!("there are no years")),
+        Some(year) => match year.parse() {
+            Ok(year) if years.contains(&year) => Ok(year),
+            _ => Err(cli_error!(
+                "year {} does not exist, available years are {}",
+                year,
+                available()
+            )),
+        },
+    }
+}
+
+fn parse_command(arg: &str) -> Option<Command> {
+    match arg {
+        "run" => Some(Command::Run),
+        "check" => Some(Command::Check),
+        "list" => Some(Command::List),
+        "bench" => Some(Command::Bench),
+        "new-day" => Some(Command::NewDay),
+        _ => None,
+    }
+}
+
+fn parse_count(value: &str, name: &str) -> Result<usize, CliError> {
+    match value.parse() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(cli_error!(
+            "invalid {} '{}': expected a positive number",
+            name,
+            value
+        )),
+    }
+}
+
+/// Parses a comma separated list of days and day ranges, e.g. `1-5,12,20-`
+///
+/// Single days must be `available`, ranges select all available days within them.
+pub fn parse_days(selector: &str, available: &[usize]) -> Result<Vec<usize>, CliError> {
+    let invalid = || cli_error!("invalid days '{}': expected e.g. 1-5,12,20-", selector);
+    let day = |day: &str, default: usize| -> Result<usize, CliError> {
+        if day.is_empty() {
+            return Ok(default);
+        }
+        day.parse().map_err(|_| {
+            cli_error!(
+                "invalid day '{}' in '{}': expected e.g. 1-5,12,20-",
+                day,
+                selector
+            )
+        })
+    };
+    let (first, last) = match available {
+        [] => return Err(cli_error!("there are no days")),
+        [first, .., last] => (*first, *last),
+        [day] => (*day, *day),
+    };
+
+    let mut days = vec![];
+    for item in selector.split(',').map(str::trim) {
+        if item.is_empty() || item == "-" {
+            return Err(invalid());
+        }
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (day(start, first)?, day(end, last)?);
+                if start > end {
+                    return Err(cli_error!("invalid day range '{}'", item));
+                }
+                let range = available.iter().filter(|day| (start..=end).contains(day));
+                if range.clone().next().is_none() {
+                    return Err(cli_error!(
+                        "no days in '{}', available days are {}",
+                        item,
+                        format_days(available)
+                    ));
+                }
+                days.extend(range);
+            }
+            None => match day(item, 0)? {
+                day if available.contains(&day) => days.push(day),
+                day => {
+                    return Err(cli_error!(
+                        "day {} does not exist, available days are {}",
+                        day,
+                        format_days(available)
+                    ))
+                }
+            },
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+/// Formats days compactly as ranges, e.g. `1-5, 12, 20-25`
+pub fn format_days(days: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = vec![];
+    for &day in days {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == day => *end = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|(start, end)| match start == end {
+            true => start.to_string(),
+            false => format!("{}-{}", start, end),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn all_days() -> Vec<usize> {
+        (1..=25).collect()
+    }
+
+    fn parse(args: &str) -> Result<Options, CliError> {
+        let args = args
+            .split_whitespace()
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        Options::parse(&args, 2022, &all_days())
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(
+            parse_days("1-3,12,5,23-", &all_days()),
+            Ok(vec![1, 2, 3, 5, 12, 23, 24, 25])
+        );
+        assert_eq!(parse_days("-2", &all_days()), Ok(vec![1, 2]));
+        assert_eq!(
+            parse_days("26", &all_days()),
+            Err(cli_error!("day 26 does not exist, available days are 1-25"))
+        );
+        assert!(parse_days("5-3", &all_days()).is_err());
+        assert!(parse_days("1,,2", &all_days()).is_err());
+        assert!(parse_days("x", &all_days()).is_err());
+
+        let sparse = [1, 2, 3, 17, 20];
+        assert_eq!(parse_days("2-18", &sparse), Ok(vec![2, 3, 17]));
+        assert_eq!(
+            parse_days("5", &sparse),
+            Err(cli_error!(
+                "day 5 does not exist, available days are 1-3, 17, 20"
+            ))
+        );
+        assert!(parse_days("4-16", &sparse).is_err());
+    }
+
+    #[test]
+    fn commands_and_options() {
+        let options = parse("check 1-3 2 --input foo --runs=3").unwrap();
+        assert_eq!(options.command, Command::Check);
+        assert_eq!(options.days, vec![1, 2, 3]);
+        assert_eq!(options.part, Some(2));
+        assert_eq!(options.input, Input::Path(PathBuf::from("foo")));
+        assert_eq!(options.runs, Some(3));
+
+        let options = parse("7 --input -").unwrap();
+        assert_eq!(options.command, Command::Run);
+        assert_eq!(options.days, vec![7]);
+        assert_eq!(options.input, Input::Stdin);
+
+        assert_eq!(parse("bench").unwrap().runs, Some(BENCH_RUNS));
+        assert_eq!(parse("12 --example").unwrap().input, Input::Example);
+        assert_eq!(parse("--format=json").unwrap().format, Format::Json);
+        assert_eq!(
+            parse("--format xml"),
+            Err(cli_error!(
+                "invalid format 'xml': expected human, plain or json"
+            ))
+        );
+        assert_eq!(
+            parse("new-day 26"),
+            Err(cli_error!("invalid day '26': expected 1-25"))
+        );
+
+        let options = parse("bench --baseline --threshold=5% --save-baseline=new.csv").unwrap();
+        assert_eq!(
+            options.baseline,
+            Some(input_dir(".", 2022).join(BASELINE_FILE))
+        );
+        assert_eq!(options.threshold, 5.0);
+        assert_eq!(
+            options.exports,
+            vec![(PathBuf::from("new.csv"), ExportFormat::Csv)]
+        );
+
+        let options = parse("15 --param 15.row=10 --param=15.area=20").unwrap();
+        assert_eq!(
+            options.params.get(15).collect::<Vec<_>>(),
+            vec![("area", "20"), ("row", "10")]
+        );
+        assert!(!options.params_given);
+
+        assert_eq!(parse("1").unwrap().cache, None);
+        assert_eq!(
+            parse("1 --cache").unwrap().cache,
+            Some(PathBuf::from(CACHE_DIR).join("2022.csv"))
+        );
+        assert_eq!(parse("1 --cache --no-cache").unwrap().cache, None);
+        assert!(parse("1 --allocations").unwrap().allocations);
+        assert_eq!(
+            parse("1 --trace=trace.json").unwrap().trace,
+            Some(PathBuf::from("trace.json"))
+        );
+    }
+
+    #[test]
+    fn years() {
+        let args = |args: &str| {
+            args.split_whitespace()
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        };
+        let years = [2021, 2022, 2023];
+        assert_eq!(parse_year(&args("run 1"), &years), Ok(2023));
+        assert_eq!(parse_year(&args("1 --year 2021"), &years), Ok(2021));
+        assert_eq!(parse_year(&args("--year=2022 1"), &years), Ok(2022));
+        assert_eq!(
+            parse_year(&args("--year 2020"), &years),
+            Err(cli_error!(
+                "year 2020 does not exist, available years are 2021, 2022, 2023"
+            ))
+        );
+        assert!(parse_year(&args("--year"), &years).is_err());
+
+        let options = parse("--year 2022").unwrap();
+        assert_eq!(options.year, 2022);
+        assert_eq!(options.answers, input_dir(".", 2022).join(ANSWERS_FILE));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse("run 1 3"),
+            Err(cli_error!("invalid part '3': expected 1 or 2"))
+        );
+        assert_eq!(
+            parse("--input"),
+            Err(cli_error!("option '--input' expects a value"))
+        );
+        assert_eq!(parse("--foo"), Err(cli_error!("unknown option '--foo'")));
+        assert_eq!(
+            parse("run 1 2 3"),
+            Err(cli_error!("unexpected argument '3'"))
+        );
+        assert!(parse("bench --timeout=1").is_err());
+        assert_eq!(
+            parse("--allocations --parallel"),
+            Err(cli_error!(
+                "'--allocations' can not be combined with '--parallel' or '--timeout'"
+            ))
+        );
+        assert!(parse("--allocations --timeout=1").is_err());
+        assert!(parse("--cache --save-baseline").is_err());
+        assert!(parse("--cache --export timings.md").is_err());
+        assert_eq!(
+            parse("--param row=10"),
+            Err(cli_error!(
+                "invalid parameter 'row=10': expected <day>.<name>=<value>"
+            ))
+        );
+        assert!(parse("--param 26.row=10").is_err());
+        assert_eq!(
+            parse("--input foo --example"),
+            Err(cli_error!("'--example' can not be combined with '--input'"))
+        );
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::str::FromStr;
 
+use aoc_runner::ParseError;
+
 use combine::{
     optional,
     parser::{
@@ -27,6 +29,13 @@ macro_rules! number_parser {
     };
 }
 
+/// Parses `token`, a slice of `input`, reporting its position inside `input` on failure
+pub fn parse_at<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
 #[allow(dead_code)]
 pub fn int<Input, Output: PrimInt + FromStr>() -> impl Parser<Input, Output = Output>
 where
@@ -1,131 +1,70 @@
-//! Day x <name>
+//! # Day 00 <name>
 //!
 //! <short description of problem>
 //!
 //! <short description of solution>
 
-use crate::common::day::Day;
+use aoc_runner::{Day, Example, ParseError};
 
-#[derive(Clone, Default)]
-pub(crate) struct Day00 { commands: Vec<Command> }
+#[derive(Default, Clone)]
+pub struct Day00(Vec<u32>);
 
 impl Day for Day00 {
-    fn part1(&mut self, ) {
-        println!("{:?}", self.commands)
+    type Result1 = u32;
+    type Result2 = u32;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: None,
+        part2: None,
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.0 = parser::parse(input)?;
+        Ok(())
     }
 
-    fn part2(&mut self, ) {
-        println!("part 2")
+    fn part1(&mut self) -> Self::Result1 {
+        self.0.iter().sum()
     }
 
-    fn parse(&mut self, input: &str) {
-        self.commands = parser::parse(input).unwrap().0;
+    fn part2(&mut self) -> Self::Result2 {
+        self.0.iter().max().copied().unwrap_or_default()
     }
-
-}
-
-pub fn part1(input: &str) {
-    let commands = parser::parse(input).unwrap().0;
-    println!("{:?}", commands)
-}
-
-pub fn part2(_input: &str) {
-    println!("part 2")
-}
-
-#[derive(Debug, Clone)]
-enum Command {
-    Rect(u32, u32),
-    Rotate(RotationType, RotationAxis, i32, i32),
-}
-
-#[derive(Debug, Clone)]
-enum RotationType {
-    Row,
-    Column,
-}
-
-#[derive(Debug, Clone)]
-enum RotationAxis {
-    X,
-    Y,
 }
 
 mod parser {
-    use super::*;
-    use crate::common::parser::int;
-    use combine::{
-        attempt,
-        choice,
-        sep_end_by,
-        parser::char::{char, string, spaces, digit},
-        stream::easy::ParseError,
-        stream::easy::Stream,
-        EasyParser, Parser,
-    };
-
-    pub(super) fn parse(input: &str) -> Result<(Vec<Command>, &str), ParseError<&str>> {
-        parse_commands().easy_parse(input)
-    }
-
-    fn parse_commands<'a>() -> impl Parser<Stream<&'a str>, Output = Vec<Command>> {
-        sep_end_by(parse_command(), char('\n'))
-    }
+    use aoc_runner::ParseError;
 
-    fn parse_command<'a>() -> impl Parser<Stream<&'a str>, Output = Command> {
-        choice((
-            attempt(parse_rect()),
-            attempt(parse_rotate()),
-        ))
-    }
+    use crate::common::parser::parse_at;
 
-    fn parse_rect<'a>() -> impl Parser<Stream<&'a str>, Output = Command> {
-        (string("rect "), int(), char('x'), int())
-            .map(|(_, x, _, y)| Command::Rect(x, y))
-    }
-
-    fn parse_rotate<'a>() -> impl Parser<Stream<&'a str>, Output = Command> {
-        (
-            string("rotate "),
-            parse_rotation_type(),
-            spaces(),
-            parse_rotation_axis(),
-            char('='),
-            int(),
-            string(" by "),
-            int(),
-        )
-            .map(
-                |(_, rotation_type, _, rotation_axis, _, value, _, by_value)| {
-                    Command::Rotate(rotation_type, rotation_axis, value, by_value)
-                },
-            )
-    }
-
-    fn parse_rotation_type<'a>() -> impl Parser<Stream<&'a str>, Output = RotationType> {
-        choice((
-            string("row ").map(|_| RotationType::Row),
-            string("column ").map(|_| RotationType::Column),
-        ))
-    }
-
-    fn parse_rotation_axis<'a>() -> impl Parser<Stream<&'a str>, Output = RotationAxis> {
-        choice((
-            string("x").map(|_| RotationAxis::X),
-            string("y").map(|_| RotationAxis::Y),
-        ))
+    pub(super) fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_at(input, line, "number"))
+            .collect()
     }
 }
 
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "";
+
 #[cfg(test)]
 mod test {
+    use super::*;
+
     #[test]
-    fn test_part1() {
-        assert!(false);
+    fn part_1() {
+        let mut day = Day00::default();
+        day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(), 0);
     }
 
     #[test]
-    fn test_part2() {
-        assert!(false);
+    fn part_2() {
+        let mut day = Day00::default();
+        day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part2(), 0);
     }
 }
@@ -5,7 +5,9 @@
 //! - a) the group with the max sum
 //! - b) the sum of the three groups with most sums
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
+
+use crate::common::parser::parse_at;
 
 #[derive(Default, Clone)]
 pub struct Day01(Vec<Vec<u32>>);
@@ -14,11 +16,23 @@ impl Day for Day01 {
     type Result1 = u32;
     type Result2 = u32;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("24000"),
+        part2: Some("45000"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input
             .split("\n\n")
-            .map(|s| s.lines().map(str::parse).map(Result::unwrap).collect())
-            .collect();
+            .map(|s| {
+                s.lines()
+                    .map(|line| parse_at(input, line, "number"))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -36,11 +50,8 @@ impl Day for Day01 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "1000
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "1000
 2000
 3000
 
@@ -56,17 +67,21 @@ mod test {
 10000
 ";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day01::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 24000);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day01::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 45000);
     }
 }
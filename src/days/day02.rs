@@ -5,7 +5,7 @@
 //! - a) the group with the max sum
 //! - b) the sum of the three groups with most sums
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 
 #[derive(Default, Clone)]
 pub struct Day02(Vec<(char, char)>);
@@ -14,11 +14,22 @@ impl Day for Day02 {
     type Result1 = u32;
     type Result2 = u32;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("15"),
+        part2: Some("12"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input
             .lines()
-            .map(|line| (line.chars().next().unwrap(), line.chars().nth(2).unwrap()))
-            .collect();
+            .map(|line| match line.as_bytes() {
+                [a @ b'A'..=b'C', b' ', b @ b'X'..=b'Z'] => Ok((*a as char, *b as char)),
+                _ => Err(ParseError::at(input, line, "'[ABC] [XYZ]'")),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -82,26 +93,27 @@ impl Day for Day02 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "A Y
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "A Y
 B X
 C Z
 ";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day02::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 15);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day02::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 12);
     }
 }
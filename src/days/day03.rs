@@ -7,7 +7,7 @@
 //!
 //! and sum them up
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 
 #[derive(Default, Clone)]
 pub struct Day03(Vec<Vec<u8>>);
@@ -37,20 +37,36 @@ impl Day for Day03 {
     type Result1 = u32;
     type Result2 = u32;
 
-    fn parse(&mut self, input: &str) {
-        fn char_to_priority(c: char) -> u8 {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("157"),
+        part2: Some("70"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        fn char_to_priority(c: char) -> Option<u8> {
             let i: u32 = c.into();
             (match c {
-                'a'..='z' => i - 96, // map 97 to 1
-                'A'..='Z' => i - 38, // map 65 to 27
-                _ => panic!("Got bad char '{}'", c),
-            }) as u8
+                'a'..='z' => Some(i - 96), // map 97 to 1
+                'A'..='Z' => Some(i - 38), // map 65 to 27
+                _ => None,
+            })
+            .map(|i| i as u8)
         }
 
         self.0 = input
             .lines()
-            .map(|line| line.chars().into_iter().map(char_to_priority).collect())
-            .collect();
+            .map(|line| {
+                line.char_indices()
+                    .map(|(idx, c)| {
+                        char_to_priority(c)
+                            .ok_or_else(|| ParseError::at(input, &line[idx..], "letter"))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -71,11 +87,8 @@ impl Day for Day03 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
 jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
 PmmdzqPrVvPwwTWBwg
 wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
@@ -83,17 +96,21 @@ ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw
 ";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day03::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 157);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day03::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 70);
     }
 }
@@ -4,7 +4,9 @@
 //! - a) one pair is fully contained inside the other
 //! - b) the paris overlap
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
+
+use crate::common::parser::parse_at;
 
 type Range = (u32, u32);
 
@@ -15,18 +17,30 @@ impl Day for Day04 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
-        fn to_range(s: &str) -> Range {
-            let mut iter = s.split('-').map(|s| s.parse().expect("Bad number"));
-            (iter.next().unwrap(), iter.next().unwrap())
-        }
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("2"),
+        part2: Some("4"),
+        params: &[],
+    });
 
-        fn to_ranges(s: &str) -> (Range, Range) {
-            let mut iter = s.split(',').map(to_range);
-            (iter.next().unwrap(), iter.next().unwrap())
-        }
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        let to_range = |s: &str| -> Result<Range, ParseError> {
+            let (a, b) = s
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, s, "range 'a-b'"))?;
+            Ok((parse_at(input, a, "number")?, parse_at(input, b, "number")?))
+        };
 
-        self.0 = input.lines().map(to_ranges).collect();
+        let to_ranges = |s: &str| -> Result<(Range, Range), ParseError> {
+            let (a, b) = s
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(input, s, "pair of ranges 'a-b,c-d'"))?;
+            Ok((to_range(a)?, to_range(b)?))
+        };
+
+        self.0 = input.lines().map(to_ranges).collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -46,28 +60,29 @@ impl Day for Day04 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "2-4,6-8
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "2-4,6-8
 2-3,4-5
 5-7,7-9
 2-8,3-7
 6-6,4-6
 2-6,4-8";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day04::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 2);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day04::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 4);
     }
 }
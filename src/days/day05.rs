@@ -9,8 +9,8 @@
 
 use std::collections::VecDeque;
 
-use crate::common::transpose::Transpose;
-use aoc_runner::Day;
+use crate::common::{parser::parse_at, transpose::Transpose};
+use aoc_runner::{Day, Example, ParseError};
 
 type Tower = VecDeque<char>;
 type Towers = Vec<Tower>;
@@ -30,7 +30,16 @@ impl Day for Day05 {
     type Result1 = String;
     type Result2 = String;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("CMZ"),
+        part2: Some("MCD"),
+        params: &[],
+    });
+
+    const TRIM_INPUT: bool = false;
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         fn parse_towers(input: &str) -> Towers {
             input
                 .lines()
@@ -51,27 +60,24 @@ impl Day for Day05 {
                 .collect_with(|inner| inner.flatten().collect())
         }
 
-        fn parse_moves(input: &str) -> Vec<Move> {
-            input.lines().map(parse_move).collect()
-        }
-
-        fn parse_move(input: &str) -> Move {
-            let words = input.split(' ').collect::<Vec<&str>>();
-            Move {
-                count: words[1].parse().unwrap(),
-                from: words[3].parse::<usize>().unwrap() - 1,
-                to: words[5].parse::<usize>().unwrap() - 1,
+        let parse_move = |line: &str| -> Result<Move, ParseError> {
+            match line.split(' ').collect::<Vec<&str>>()[..] {
+                ["move", count, "from", from, "to", to] => Ok(Move {
+                    count: parse_at(input, count, "number")?,
+                    from: parse_at::<usize>(input, from, "stack number")?.saturating_sub(1),
+                    to: parse_at::<usize>(input, to, "stack number")?.saturating_sub(1),
+                }),
+                _ => Err(ParseError::at(input, line, "'move <n> from <a> to <b>'")),
             }
-        }
-
-        let (towers, moves) = {
-            let mut parts = input.split("\n\n");
-            let (towers, moves) = (parts.next().unwrap(), parts.next().unwrap());
-            (parse_towers(towers), parse_moves(moves))
         };
 
-        self.towers = towers;
-        self.moves = moves;
+        let (towers, moves) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at(input, &input[input.len()..], "empty line"))?;
+
+        self.towers = parse_towers(towers);
+        self.moves = moves.lines().map(parse_move).collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -103,11 +109,8 @@ impl Day for Day05 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "    [D]    
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "    [D]    
 [N] [C]    
 [Z] [M] [P]
  1   2   3 
@@ -118,17 +121,21 @@ move 2 from 2 to 1
 move 1 from 1 to 2
 ";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day05::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), String::from("CMZ"));
     }
 
     #[test]
     fn part_2() {
         let mut day = Day05::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), String::from("MCD"));
     }
 }
@@ -7,7 +7,7 @@
 //!
 //! chars are all different
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 
 #[derive(Default)]
 pub struct Day06(String);
@@ -45,8 +45,16 @@ impl Day for Day06 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("11"),
+        part2: Some("26"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input.to_owned();
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -58,28 +66,30 @@ impl Day for Day06 {
     }
 }
 
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+
 #[cfg(test)]
 mod test {
     use super::*;
 
-    const INPUT1: &str = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
     const INPUT2: &str = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
 
     #[test]
     fn part_1() {
         let mut day = Day06::default();
-        day.parse(INPUT1);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 11);
-        day.parse(INPUT2);
+        day.parse(INPUT2).unwrap();
         assert_eq!(day.part1(), 10);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day06::default();
-        day.parse(INPUT1);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 26);
-        day.parse(INPUT2);
+        day.parse(INPUT2).unwrap();
         assert_eq!(day.part2(), 29);
     }
 }
@@ -5,7 +5,7 @@
 //! - a) all dirs that contain less than 100000 bytes in files
 //! - b) the smallest dir to remove to obtain 30000000 bytes of free disk space
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 use rustc_hash::FxHashMap as HashMap;
 
 const DISK_SIZE: usize = 70000000;
@@ -19,8 +19,16 @@ impl Day for Day07 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("95437"),
+        part2: Some("24933642"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input.split('\n').map(str::to_owned).collect();
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -71,10 +79,8 @@ impl Day for Day07 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT: &str = "$ cd /
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "$ cd /
 $ ls
 dir a
 14848514 b.txt
@@ -98,17 +104,21 @@ $ ls
 5626152 d.ext
 7214296 k";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day07::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 95437);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day07::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 24933642);
     }
 }
@@ -8,11 +8,11 @@
 //! Where for
 //!
 //! - a) a tree is visible from the edge if there is no bigger tree between the edge and the three
-//! in question itself
+//!   in question itself
 //! - b) a tree is visible from an inner tree as if there is no tree bigger then the inner tree
-//! between the inner tree and the tree in question
+//!   between the inner tree and the tree in question
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 // use std::collections::HashSet;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -23,8 +23,16 @@ impl Day for Day08 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("21"),
+        part2: Some("8"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input.lines().map(|line| line.chars().collect()).collect();
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -132,7 +140,7 @@ impl Day for Day08 {
 
     fn part2(&mut self) -> Self::Result2 {
         #[inline(always)]
-        fn scenic_distance(map: &Vec<Vec<char>>, x: usize, y: usize) -> usize {
+        fn scenic_distance(map: &[Vec<char>], x: usize, y: usize) -> usize {
             let height = map.len();
             let width = map[0].len();
             let tree = map[y][x];
@@ -217,26 +225,28 @@ impl Day for Day08 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT: &str = "30373
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "30373
 25512
 65332
 33549
 35390";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day08::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 21);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day08::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 8);
     }
 }
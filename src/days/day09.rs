@@ -7,16 +7,18 @@
 //!
 //! behaves while moving and track all fields the rope's tail visits
 
-use aoc_runner::Day;
+use crate::common::parser::parse_at;
+use aoc_runner::{Day, Example, ParseError};
 use rustc_hash::FxHashSet as HashSet;
 #[cfg(debug_assertions)]
 use std::collections::HashMap;
 
-#[derive(Default)]
-pub struct Day09(String);
-
 type Knot = (i32, i32);
 
+/// A list of movements, given as (direction, steps)
+#[derive(Default)]
+pub struct Day09(Vec<(Knot, usize)>);
+
 /// Given a moved knot `head`, let `tail` follow `head` and return its new position
 #[inline(always)]
 fn follow_knot(head: Knot, mut tail: Knot) -> Knot {
@@ -59,24 +61,40 @@ fn print_rope(rope: &[Knot]) {
     }
 }
 
-macro_rules! diff {
-    ($dir: expr) => {
-        match $dir {
-            "U" => (0, -1),
-            "D" => (0, 1),
-            "L" => (-1, 0),
-            "R" => (1, 0),
-            _ => panic!("Bad direction: {}", $dir),
-        }
-    };
+/// Translate a direction into a position delta
+fn diff(dir: &str) -> Option<Knot> {
+    match dir {
+        "U" => Some((0, -1)),
+        "D" => Some((0, 1)),
+        "L" => Some((-1, 0)),
+        "R" => Some((1, 0)),
+        _ => None,
+    }
 }
 
 impl Day for Day09 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
-        self.0 = input.to_owned();
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("13"),
+        part2: None,
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.0 = input
+            .lines()
+            .map(|line| {
+                let (dir, steps) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(input, line, "'<direction> <steps>'"))?;
+                let diff = diff(dir).ok_or_else(|| ParseError::at(input, dir, "U, D, L or R"))?;
+                Ok((diff, parse_at(input, steps, "number")?))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -88,14 +106,7 @@ impl Day for Day09 {
             set
         };
 
-        for line in self.0.lines() {
-            let (dir, steps): (&str, usize) = line
-                .split_once(' ')
-                .map(|(dir, steps)| (dir, steps.parse().unwrap()))
-                .unwrap();
-
-            let diff = diff!(dir);
-
+        for &(diff, steps) in self.0.iter() {
             for _ in 0..steps {
                 head.0 += diff.0;
                 head.1 += diff.1;
@@ -117,14 +128,7 @@ impl Day for Day09 {
             set
         };
 
-        for line in self.0.lines() {
-            let (dir, steps): (&str, usize) = line
-                .split_once(' ')
-                .map(|(dir, steps)| (dir, steps.parse().unwrap()))
-                .unwrap();
-
-            let diff = diff!(dir);
-
+        for &(diff, steps) in self.0.iter() {
             for _ in 0..steps {
                 let head = &mut knots[0];
                 head.0 += diff.0;
@@ -152,10 +156,8 @@ impl Day for Day09 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT1: &str = "R 4
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "R 4
 U 4
 L 3
 D 1
@@ -164,6 +166,9 @@ D 1
 L 5
 R 2";
 
+#[cfg(test)]
+mod test {
+    use super::*;
     const INPUT2: &str = "R 5
 U 8
 L 8
@@ -176,14 +181,27 @@ U 20";
     #[test]
     fn part_1() {
         let mut day = Day09::default();
-        day.parse(INPUT1);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 13);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day09::default();
-        day.parse(INPUT2);
+        day.parse(INPUT2).unwrap();
         assert_eq!(day.part2(), 36);
     }
+
+    #[test]
+    fn parse_error() {
+        let mut day = Day09::default();
+        assert_eq!(
+            day.parse("R 4\nX 2"),
+            Err(ParseError::new(2, 1, "U, D, L or R"))
+        );
+        assert_eq!(
+            day.parse("R 4\nU four"),
+            Err(ParseError::new(2, 3, "number"))
+        );
+    }
 }
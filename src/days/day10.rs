@@ -9,12 +9,14 @@
 //!
 //! - a) calculate the register values, multiplied with the cycle value at cycles 20, 60, 100, 140, 180, 220
 //! - b) treat the register value at cycle `n` as position of a '###' sprite at time `n` and draw
-//! a 40px * 60px based on the fact if the pixel that will be drawn at time `n` is blocked by the sprite
+//!   a 40px * 60px based on the fact if the pixel that will be drawn at time `n` is blocked by the sprite
 //!
 
 use std::fmt::Write;
 
-use aoc_runner::Day;
+use aoc_runner::{Answer, Day, Example, ParseError};
+
+use crate::common::parser::parse_at;
 
 /// Visual representation of the 40px * 6px CRT output
 pub struct Crt([bool; 240]);
@@ -32,6 +34,17 @@ impl std::fmt::Debug for Crt {
     }
 }
 
+/// The pixels drawn as in the puzzle's description, `#` for lit and `.` for dark pixels
+impl Answer for Crt {
+    fn answer(&self) -> String {
+        self.0
+            .chunks(40)
+            .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
 #[derive(Default)]
 pub struct Day10(Vec<Option<i32>>);
 
@@ -39,11 +52,23 @@ impl Day for Day10 {
     type Result1 = i32;
     type Result2 = Crt;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("13140"),
+        part2: Some(EXAMPLE_IMAGE),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input
             .lines()
-            .map(|line| line.split_once(' ').and_then(|(_, num)| num.parse().ok()))
-            .collect();
+            .map(|line| match line.split_once(' ') {
+                None if line == "noop" => Ok(None),
+                Some(("addx", num)) => parse_at(input, num, "number").map(Some),
+                _ => Err(ParseError::at(input, line, "'noop' or 'addx <number>'")),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -94,10 +119,8 @@ impl Day for Day10 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT: &str = "addx 15
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "addx 15
 addx -11
 addx 6
 addx -3
@@ -244,24 +267,30 @@ noop
 noop
 noop";
 
+/// The image the example draws in part 2
+const EXAMPLE_IMAGE: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day10::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 13140);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day10::default();
-        day.parse(INPUT);
-        let expected_str = "##..##..##..##..##..##..##..##..##..##..
-###...###...###...###...###...###...###.
-####....####....####....####....####....
-#####.....#####.....#####.....#####.....
-######......######......######......####
-#######.......#######.......#######.....";
-        let expected_vec = expected_str
+        day.parse(EXAMPLE).unwrap();
+        let expected_vec = EXAMPLE_IMAGE
             .chars()
             .filter(|&c| c != '\n')
             .map(|c| c == '#')
@@ -272,5 +301,6 @@ noop";
         println!("Expected: {:?}", expected);
         println!("Actual: {:?}", actual);
         assert_eq!(expected.0, actual.0);
+        assert_eq!(actual.answer(), EXAMPLE_IMAGE);
     }
 }
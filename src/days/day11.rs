@@ -16,9 +16,11 @@
 
 use std::collections::VecDeque;
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 
-#[derive(Default)]
+use crate::common::parser::parse_at;
+
+#[derive(Default, Clone)]
 struct Monkeys {
     monkeys: Vec<Monkey>,
     lcm: usize,
@@ -35,7 +37,6 @@ impl Monkeys {
 /// A babbling, bumbling band of baboons
 impl Monkeys {
     /// Simulate all monkeys throwing their items around for one round
-
     #[inline(always)]
     fn do_round_with_relief(&mut self) {
         for idx in 0..self.monkeys.len() {
@@ -77,6 +78,7 @@ impl Monkeys {
     }
 }
 
+#[derive(Clone)]
 struct Monkey {
     throw_count: usize,
     items: VecDeque<usize>,
@@ -84,6 +86,7 @@ struct Monkey {
     test: Test,
 }
 
+#[derive(Clone)]
 enum Operation {
     Add(usize),
     Multiply(usize),
@@ -102,6 +105,7 @@ impl Operation {
     }
 }
 
+#[derive(Clone)]
 struct Test {
     divisor: usize,
     monkey_true: usize,
@@ -115,84 +119,76 @@ impl Day for Day11 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("10605"),
+        part2: Some("2713310158"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         let monkeys: Vec<Monkey> = input
             .split("\n\n")
-            .into_iter()
-            .map(|lines| {
-                let mut lines = lines.split('\n').map(str::trim);
-                let (items_line, operation_line, divisible_line, true_line, false_line) = {
-                    lines.next();
-                    (
-                        lines.next().unwrap(),
-                        lines.next().unwrap(),
-                        lines.next().unwrap(),
-                        lines.next().unwrap(),
-                        lines.next().unwrap(),
-                    )
+            .map(|block| {
+                let mut lines = block.split('\n').map(str::trim);
+                let mut next_line = |prefix: &str| {
+                    let line = lines.next().unwrap_or(&block[block.len()..]);
+                    line.strip_prefix(prefix)
+                        .ok_or_else(|| ParseError::at(input, line, format!("'{}'", prefix)))
                 };
+                next_line("Monkey ")?;
+                let (items_line, operation_line, divisible_line, true_line, false_line) = (
+                    next_line("Starting items: ")?,
+                    next_line("Operation: new = old ")?,
+                    next_line("Test: divisible by ")?,
+                    next_line("If true: throw to monkey ")?,
+                    next_line("If false: throw to monkey ")?,
+                );
 
                 let items: VecDeque<usize> = items_line
-                    .strip_prefix("Starting items: ")
-                    .map(|part| part.split(", ").map(|num| num.parse().unwrap()))
-                    .unwrap()
-                    .collect();
-                let operation = operation_line
-                    .strip_prefix("Operation: new = old ")
-                    .and_then(|rest| rest.split_once(' '))
-                    .map(|(op, num)| match (op, num) {
-                        ("+", "old") => Operation::Double,
-                        ("*", "old") => Operation::Square,
-                        ("+", _) => Operation::Add(
-                            num.parse().unwrap_or_else(|_| panic!("Bad num: {}", num)),
-                        ),
-                        ("*", _) => Operation::Multiply(
-                            num.parse().unwrap_or_else(|_| panic!("Bad num {}", num)),
-                        ),
-                        _ => panic!("Unknown operation {}", op),
-                    })
-                    .unwrap();
-                let test = {
-                    let divisor = divisible_line
-                        .split(' ')
-                        .last()
-                        .and_then(|num| num.parse().ok())
-                        .unwrap();
-                    let monkey_true = true_line
-                        .split(' ')
-                        .last()
-                        .and_then(|num| num.parse().ok())
-                        .unwrap();
-                    let monkey_false = false_line
-                        .split(' ')
-                        .last()
-                        .and_then(|num| num.parse().ok())
-                        .unwrap();
-                    Test {
-                        divisor,
-                        monkey_true,
-                        monkey_false,
+                    .split(", ")
+                    .map(|num| parse_at(input, num, "number"))
+                    .collect::<Result<_, _>>()?;
+                let operation = match operation_line.split_once(' ') {
+                    Some(("+", "old")) => Operation::Double,
+                    Some(("*", "old")) => Operation::Square,
+                    Some(("+", num)) => Operation::Add(parse_at(input, num, "number")?),
+                    Some(("*", num)) => Operation::Multiply(parse_at(input, num, "number")?),
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            operation_line,
+                            "'+ <number>', '* <number>', '+ old' or '* old'",
+                        ))
                     }
                 };
+                let test = Test {
+                    divisor: parse_at(input, divisible_line, "number")?,
+                    monkey_true: parse_at(input, true_line, "monkey number")?,
+                    monkey_false: parse_at(input, false_line, "monkey number")?,
+                };
 
-                Monkey {
+                Ok(Monkey {
                     throw_count: 0,
                     items,
                     operation,
                     test,
-                }
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         self.0 = Monkeys::new(monkeys);
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
+        // Rounds move items and count throws, so every part starts from the parsed monkeys
+        let mut monkeys = self.0.clone();
         for _ in 0..20 {
-            self.0.do_round_with_relief();
+            monkeys.do_round_with_relief();
         }
         let throw_counts = {
-            let mut counts: Vec<usize> = self.0.monkeys.iter().map(|m| m.throw_count).collect();
+            let mut counts: Vec<usize> = monkeys.monkeys.iter().map(|m| m.throw_count).collect();
             counts.sort_unstable_by(|a, b| b.cmp(a));
             counts
         };
@@ -200,11 +196,13 @@ impl Day for Day11 {
     }
 
     fn part2(&mut self) -> Self::Result2 {
+        // Rounds move items and count throws, so every part starts from the parsed monkeys
+        let mut monkeys = self.0.clone();
         for _ in 0..10000 {
-            self.0.do_round_without_relief();
+            monkeys.do_round_without_relief();
         }
         let throw_counts = {
-            let mut counts: Vec<usize> = self.0.monkeys.iter().map(|m| m.throw_count).collect();
+            let mut counts: Vec<usize> = monkeys.monkeys.iter().map(|m| m.throw_count).collect();
             counts.sort_unstable_by(|a, b| b.cmp(a));
             counts
         };
@@ -212,10 +210,8 @@ impl Day for Day11 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT: &str = "Monkey 0:
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "Monkey 0:
 Starting items: 79, 98
 Operation: new = old * 19
 Test: divisible by 23
@@ -243,17 +239,21 @@ Test: divisible by 17
   If true: throw to monkey 0
   If false: throw to monkey 1";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day11::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 10605);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day11::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 2713310158);
     }
 }
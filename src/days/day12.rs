@@ -12,7 +12,7 @@
 
 use std::collections::VecDeque;
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 
 type HeightMap = Vec<Vec<u32>>;
 
@@ -27,12 +27,30 @@ impl Day for Day12 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("31"),
+        part2: Some("29"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         let map_height = input.lines().count();
-        let map_width = input.lines().next().unwrap().len();
+        let map_width = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "height map"))?
+            .len();
         let mut map = vec![vec![0; map_width]; map_height];
         for (y, line) in input.lines().enumerate() {
-            for (x, ch) in line.chars().enumerate() {
+            if line.len() != map_width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("row of length {}", map_width),
+                ));
+            }
+            for (x, ch) in line.char_indices() {
                 match ch {
                     'S' => {
                         map[y][x] = 'a'.into();
@@ -42,14 +60,16 @@ impl Day for Day12 {
                         map[y][x] = 'z'.into();
                         self.target = (y as i32, x as i32);
                     }
-                    ch => {
+                    'a'..='z' => {
                         map[y][x] = ch.into();
                     }
+                    _ => return Err(ParseError::at(input, &line[x..], "'a'-'z', 'S' or 'E'")),
                 }
             }
         }
 
-        self.map = map
+        self.map = map;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -138,26 +158,28 @@ impl Day for Day12 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT: &str = "Sabqponm
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "Sabqponm
 abcryxxl
 accszExk
 acctuvwj
 abdefghi";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day12::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 31);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day12::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 29);
     }
 }
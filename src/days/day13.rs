@@ -9,10 +9,10 @@
 use std::{
     cmp::Ordering,
     iter::Peekable,
-    str::{Chars, FromStr},
+    str::{CharIndices, FromStr},
 };
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 
 #[derive(PartialEq, Eq, Clone)]
 enum Packet {
@@ -69,52 +69,64 @@ impl Ord for Packet {
     }
 }
 
+/// Byte offset and description of what was expected there
+type PacketError = (usize, &'static str);
+
 impl Packet {
-    fn parse(stream: &mut Peekable<Chars>) -> Self {
-        if stream.peek() == Some(&'[') {
+    fn parse(stream: &mut Peekable<CharIndices>) -> Result<Self, PacketError> {
+        if matches!(stream.peek(), Some((_, '['))) {
             Self::parse_list(stream)
         } else {
             Self::parse_num(stream)
         }
     }
 
-    fn parse_list(stream: &mut Peekable<Chars>) -> Self {
-        assert_eq!(stream.next(), Some('['));
+    fn parse_list(stream: &mut Peekable<CharIndices>) -> Result<Self, PacketError> {
+        stream.next();
         let mut vec = vec![];
         loop {
-            if stream.peek() == Some(&']') {
+            if matches!(stream.peek(), Some((_, ']'))) {
                 stream.next();
                 break;
             }
 
-            let el = Self::parse(stream);
+            let el = Self::parse(stream)?;
             vec.push(el);
 
-            if stream.peek() == Some(&',') {
-                stream.next();
+            match stream.peek() {
+                Some((_, ',')) => {
+                    stream.next();
+                }
+                Some((_, ']')) => {}
+                Some((idx, _)) => return Err((*idx, "',' or ']'")),
+                None => return Err((usize::MAX, "',' or ']'")),
             }
         }
 
-        Packet::List(vec)
+        Ok(Packet::List(vec))
     }
 
-    fn parse_num(stream: &mut Peekable<Chars>) -> Self {
-        let mut num = 0u8;
-        while stream.peek().unwrap().is_numeric() {
-            num *= 10;
-            num += stream.next().unwrap().to_digit(10).unwrap() as u8;
+    fn parse_num(stream: &mut Peekable<CharIndices>) -> Result<Self, PacketError> {
+        let mut num = None;
+        while let Some(digit) = stream.peek().and_then(|(_, c)| c.to_digit(10)) {
+            stream.next();
+            num = Some(num.unwrap_or(0u8) * 10 + digit as u8);
         }
 
-        Packet::Num(num)
+        match (num, stream.peek()) {
+            (Some(num), _) => Ok(Packet::Num(num)),
+            (None, Some((idx, _))) => Err((*idx, "number or '['")),
+            (None, None) => Err((usize::MAX, "number or '['")),
+        }
     }
 }
 
 impl FromStr for Packet {
-    type Err = ();
+    type Err = PacketError;
 
     #[inline(always)]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Packet::parse(&mut s.chars().peekable()))
+        Packet::parse(&mut s.char_indices().peekable())
     }
 }
 
@@ -125,12 +137,30 @@ impl Day for Day13 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("13"),
+        part2: Some("140"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        let parse_packet = |line: &str| {
+            line.parse::<Packet>().map_err(|(idx, expected)| {
+                ParseError::at(
+                    input,
+                    line.get(idx..).unwrap_or(&line[line.len()..]),
+                    expected,
+                )
+            })
+        };
+
         self.0 = input
             .split("\n\n")
             .filter_map(|parts| parts.split_once('\n'))
-            .map(|(a, b)| (a.parse().unwrap(), b.parse().unwrap()))
-            .collect();
+            .map(|(a, b)| Ok((parse_packet(a)?, parse_packet(b)?)))
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -164,10 +194,8 @@ impl Day for Day13 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT: &str = "[1,1,3,1,1]
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "[1,1,3,1,1]
 [1,1,5,1,1]
 
 [[1],[2,3,4]]
@@ -191,17 +219,21 @@ mod test {
 [1,[2,[3,[4,[5,6,7]]]],8,9]
 [1,[2,[3,[4,[5,6,0]]]],8,9]";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day13::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 13);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day13::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 140);
     }
 }
@@ -11,7 +11,9 @@ use std::collections::VecDeque;
 
 use rustc_hash::FxHashSet as HashSet;
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
+
+use crate::common::parser::parse_at;
 
 type Cave = HashSet<(i32, i32)>;
 
@@ -22,7 +24,14 @@ impl Day for Day14 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("24"),
+        part2: Some("93"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         let mut cave = Cave::default();
         let mut max_y = 0;
         let mut seen_lines = HashSet::default();
@@ -36,11 +45,12 @@ impl Day for Day14 {
             let instructions = line
                 .split(" -> ")
                 .map(|part| {
-                    part.split_once(',')
-                        .and_then(|(x, y)| Some((x.parse::<i32>().ok()?, y.parse::<i32>().ok()?)))
-                        .expect("Bad coordinates")
+                    let (x, y) = part
+                        .split_once(',')
+                        .ok_or_else(|| ParseError::at(input, part, "coordinates 'x,y'"))?;
+                    Ok((parse_at(input, x, "number")?, parse_at(input, y, "number")?))
                 })
-                .collect::<Vec<(i32, i32)>>();
+                .collect::<Result<Vec<(i32, i32)>, ParseError>>()?;
 
             for window in instructions.windows(2) {
                 match window {
@@ -59,29 +69,32 @@ impl Day for Day14 {
         }
         self.0 = cave;
         self.1 = max_y;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
         let max_y = self.1;
+        // Sand piles up in a copy, so the other part starts from the empty cave
+        let mut cave = self.0.clone();
         let mut n = 0;
 
         let mut stack: VecDeque<(i32, i32)> = VecDeque::new();
         'outer: loop {
             let (mut x, mut y) = stack.pop_front().unwrap_or((500, 0));
             loop {
-                if !self.0.contains(&(x, y + 1)) {
+                if !cave.contains(&(x, y + 1)) {
                     stack.push_front((x, y));
                     y += 1;
-                } else if !self.0.contains(&(x - 1, y + 1)) {
+                } else if !cave.contains(&(x - 1, y + 1)) {
                     stack.push_front((x, y));
                     x -= 1;
                     y += 1;
-                } else if !self.0.contains(&(x + 1, y + 1)) {
+                } else if !cave.contains(&(x + 1, y + 1)) {
                     stack.push_front((x, y));
                     x += 1;
                     y += 1;
                 } else {
-                    self.0.insert((x, y));
+                    cave.insert((x, y));
                     n += 1;
 
                     continue 'outer;
@@ -98,25 +111,27 @@ impl Day for Day14 {
 
     fn part2(&mut self) -> Self::Result2 {
         let floor_y = self.1 + 2;
+        // Sand piles up in a copy, so the other part starts from the empty cave
+        let mut cave = self.0.clone();
 
         let mut n = 0;
         let mut stack: VecDeque<(i32, i32)> = VecDeque::new();
         'outer: loop {
             let (mut x, mut y) = stack.pop_front().unwrap_or((500, 0));
             loop {
-                if !self.0.contains(&(x, y + 1)) {
+                if !cave.contains(&(x, y + 1)) {
                     stack.push_front((x, y));
                     y += 1;
-                } else if !self.0.contains(&(x - 1, y + 1)) {
+                } else if !cave.contains(&(x - 1, y + 1)) {
                     stack.push_front((x, y));
                     x -= 1;
                     y += 1;
-                } else if !self.0.contains(&(x + 1, y + 1)) {
+                } else if !cave.contains(&(x + 1, y + 1)) {
                     stack.push_front((x, y));
                     x += 1;
                     y += 1;
                 } else {
-                    self.0.insert((x, y));
+                    cave.insert((x, y));
                     n += 1;
                     if y == 0 {
                         break 'outer;
@@ -126,7 +141,7 @@ impl Day for Day14 {
                 }
 
                 if y + 1 == floor_y {
-                    self.0.insert((x, y));
+                    cave.insert((x, y));
                     n += 1;
                     continue 'outer;
                 }
@@ -137,23 +152,25 @@ impl Day for Day14 {
     }
 }
 
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
 #[cfg(test)]
 mod test {
     use super::*;
-    const INPUT: &str = "498,4 -> 498,6 -> 496,6
-503,4 -> 502,4 -> 502,9 -> 494,9";
 
     #[test]
     fn part_1() {
         let mut day = Day14::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 24);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day14::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 93);
     }
 }
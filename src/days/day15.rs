@@ -26,7 +26,10 @@
 
 use rustc_hash::FxHashMap as HashMap;
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, Params, ParseError};
+use derive_aoc_runner::Params;
+
+use crate::common::parser::parse_at;
 
 const ROW_TO_CHECK: usize = 2000000;
 const AREA_TO_CHECK: i32 = 4000000;
@@ -101,15 +104,33 @@ fn intersection_points(a: Sensor, b: Sensor) -> Vec<(i32, i32)> {
         .into_iter()
         .map(|(x, y)| (x.round() as i32, y.round() as i32))
         .filter(|(x, y)| {
-            let in_a = (x.abs_diff(a.0 .0) + y.abs_diff(a.0 .1)) as u32 <= a.1;
-            let in_b = (x.abs_diff(b.0 .0) + y.abs_diff(b.0 .1)) as u32 <= b.1;
+            let in_a = x.abs_diff(a.0 .0) + y.abs_diff(a.0 .1) <= a.1;
+            let in_b = x.abs_diff(b.0 .0) + y.abs_diff(b.0 .1) <= b.1;
             in_a && in_b
         })
         .collect()
 }
 
 #[derive(Default)]
-pub struct Day15(Vec<Sensor>);
+pub struct Day15(Vec<Sensor>, Parameters);
+
+/// The rows to check, which are much smaller in the example
+#[derive(Debug, Clone, Params)]
+pub struct Parameters {
+    /// The row to count the covered positions of in part 1
+    row: usize,
+    /// The maximum x and y coordinate of the distress beacon in part 2
+    area: i32,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters {
+            row: ROW_TO_CHECK,
+            area: AREA_TO_CHECK,
+        }
+    }
+}
 
 impl Day15 {
     fn num_covered_positions_in_row(&self, row: usize) -> usize {
@@ -187,47 +208,56 @@ impl Day for Day15 {
     type Result1 = usize;
     type Result2 = u128;
 
-    fn parse(&mut self, input: &str) {
-        fn extract_num<'a>(words: &mut impl Iterator<Item = &'a str>, nth: usize) -> i32 {
-            words
-                .nth(nth)
-                .and_then(|part| part.split_once('='))
-                .and_then(|(_, part)| {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("26"),
+        part2: Some("56000011"),
+        params: &[("row", "10"), ("area", "20")],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        let extract_num = |line: &str, words: &mut std::str::Split<char>, nth: usize| {
+            let word = words.nth(nth).unwrap_or(&line[line.len()..]);
+            word.split_once('=')
+                .map(|(_, part)| {
                     part.strip_suffix(',')
                         .or_else(|| part.strip_suffix(':'))
-                        .or(Some(part))
+                        .unwrap_or(part)
                 })
-                .and_then(|num| num.parse().ok())
-                .unwrap()
-        }
+                .ok_or_else(|| ParseError::at(input, word, "'<axis>=<number>'"))
+                .and_then(|num| parse_at::<i32>(input, num, "number"))
+        };
         self.0 = input
             .lines()
             .map(|line| {
                 let mut words = line.split(' ');
-                let sensor_x = extract_num(&mut words, 2);
-                let sensor_y = extract_num(&mut words, 0);
-                let beacon_x = extract_num(&mut words, 4);
-                let beacon_y = extract_num(&mut words, 0);
+                let sensor_x = extract_num(line, &mut words, 2)?;
+                let sensor_y = extract_num(line, &mut words, 0)?;
+                let beacon_x = extract_num(line, &mut words, 4)?;
+                let beacon_y = extract_num(line, &mut words, 0)?;
                 let radius = sensor_x.abs_diff(beacon_x) + sensor_y.abs_diff(beacon_y);
-                ((sensor_x, sensor_y), radius)
+                Ok(((sensor_x, sensor_y), radius))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
-        self.num_covered_positions_in_row(ROW_TO_CHECK)
+        self.num_covered_positions_in_row(self.1.row)
     }
 
     fn part2(&mut self) -> Self::Result2 {
-        let (x, y) = self.get_beacon_position_in_area(AREA_TO_CHECK);
+        let (x, y) = self.get_beacon_position_in_area(self.1.area);
         x as u128 * 4000000 + y as u128
     }
+
+    fn params(&mut self) -> Option<&mut dyn Params> {
+        Some(&mut self.1)
+    }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
 Sensor at x=9, y=16: closest beacon is at x=10, y=16
 Sensor at x=13, y=2: closest beacon is at x=15, y=3
 Sensor at x=12, y=14: closest beacon is at x=10, y=16
@@ -242,17 +272,24 @@ Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Day15 {
+        let mut day = Day15(vec![], Parameters { row: 10, area: 20 });
+        day.parse(EXAMPLE).unwrap();
+        day
+    }
+
     #[test]
     fn part_1() {
-        let mut day = Day15::default();
-        day.parse(INPUT);
-        assert_eq!(day.num_covered_positions_in_row(10), 26);
+        assert_eq!(example().part1(), 26);
     }
 
     #[test]
     fn part_2() {
-        let mut day = Day15::default();
-        day.parse(INPUT);
-        assert_eq!(day.get_beacon_position_in_area(20), (14, 11));
+        assert_eq!(example().get_beacon_position_in_area(20), (14, 11));
+        assert_eq!(example().part2(), 56000011);
     }
 }
@@ -11,7 +11,8 @@
 
 use std::collections::VecDeque;
 
-use aoc_runner::Day;
+use aoc_runner::{trace, Day, Example, Params, ParseError};
+use derive_aoc_runner::Params;
 use rustc_hash::FxHashMap as HashMap;
 use std::cmp::Reverse;
 use std::collections::BTreeMap;
@@ -28,55 +29,90 @@ struct Valve {
 type Valves = HashMap<ValveID, Valve>;
 
 #[derive(Default)]
-pub struct Day16(Valves, Distances);
+pub struct Day16(Valves, Distances, Parameters);
+
+/// The time limits in minutes
+#[derive(Debug, Clone, Params)]
+pub struct Parameters {
+    /// The time limit when opening valves alone, in part 1
+    time: usize,
+    /// The time limit when opening valves with the elephant, in part 2
+    time_with_elephant: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters {
+            time: 30,
+            time_with_elephant: 26,
+        }
+    }
+}
 
 impl Day for Day16 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("1651"),
+        part2: Some("1707"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         let mut lines: Vec<&str> = input.lines().collect();
         // sort so that valve "AA" always gets ID 1 << 0 == 1
         lines.sort_unstable();
 
-        assert!(
-            lines.len() < 64,
-            "Can not handle graph with more than 63 entries for now"
-        );
+        // Can not handle graph with more than 63 entries for now
+        if let Some(line) = lines.get(63) {
+            return Err(ParseError::at(input, line, "at most 63 valves"));
+        }
 
-        let name_id_map: HashMap<String, ValveID> = lines
+        let name_id_map: HashMap<&str, ValveID> = lines
             .iter()
             .enumerate()
-            .map(|(idx, line)| (line.split(' ').nth(1).unwrap().to_string(), 1 << idx))
-            .collect();
+            .map(|(idx, line)| {
+                line.split(' ')
+                    .nth(1)
+                    .map(|name| (name, 1 << idx))
+                    .ok_or_else(|| ParseError::at(input, line, "'Valve <name>'"))
+            })
+            .collect::<Result<_, _>>()?;
 
         let valves: Valves = lines
             .iter()
             .map(|line| {
                 let mut words = line.split(' ');
-                let name = words.nth(1).unwrap().to_owned();
+                let name = words.nth(1).unwrap_or_default();
                 let flow = words
                     .nth(2)
                     .and_then(|part| part.split_once('='))
                     .and_then(|(_, num)| num.strip_suffix(';'))
                     .and_then(|num| num.parse::<usize>().ok())
-                    .unwrap();
+                    .ok_or_else(|| ParseError::at(input, line, "'has flow rate=<number>;'"))?;
                 let tunnels = words
                     .skip(4)
-                    .map(|name| name.strip_suffix(',').unwrap_or(name).to_owned())
-                    .map(|name| *name_id_map.get(&name).unwrap())
-                    .collect();
+                    .map(|name| {
+                        let name = name.strip_suffix(',').unwrap_or(name);
+                        name_id_map
+                            .get(name)
+                            .copied()
+                            .ok_or_else(|| ParseError::at(input, name, "known valve name"))
+                    })
+                    .collect::<Result<_, _>>()?;
                 let valve = Valve {
-                    id: *name_id_map.get(&name).unwrap(),
+                    id: name_id_map[name],
                     flow,
                     tunnels,
                 };
 
-                (valve.id, valve)
+                Ok((valve.id, valve))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        self.1 = shortest_distances(&valves);
+        self.1 = trace::in_span("shortest_distances", || shortest_distances(&valves));
 
         self.0 = valves
             .clone()
@@ -89,16 +125,18 @@ impl Day for Day16 {
             .collect();
 
         self.0 = valves;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
-        const TIME: usize = 30;
-        dfs_max(&self.0, &self.1, TIME)
+        dfs_max(&self.0, &self.1, self.2.time)
     }
 
     fn part2(&mut self) -> Self::Result2 {
-        const TIME: usize = 26;
-        let results = dfs_with_paths(&self.0, &self.1, TIME);
+        let results = trace::in_span("dfs_with_paths", || {
+            dfs_with_paths(&self.0, &self.1, self.2.time_with_elephant)
+        });
+        let _span = trace::span("combine_paths");
 
         let results: BTreeMap<Reverse<usize>, Path> = results
             .into_iter()
@@ -121,6 +159,10 @@ impl Day for Day16 {
 
         best_score
     }
+
+    fn params(&mut self) -> Option<&mut dyn Params> {
+        Some(&mut self.2)
+    }
 }
 
 /// find score of best path (by score) that is possible in `max_time`
@@ -237,10 +279,8 @@ fn shortest_distances(valves: &Valves) -> Distances {
     distances
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    const INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
 Valve BB has flow rate=13; tunnels lead to valves CC, AA
 Valve CC has flow rate=2; tunnels lead to valves DD, BB
 Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
@@ -251,17 +291,21 @@ Valve HH has flow rate=22; tunnel leads to valve GG
 Valve II has flow rate=0; tunnels lead to valves AA, JJ
 Valve JJ has flow rate=21; tunnel leads to valve II";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day16::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 1651);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day16::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 1707);
     }
 }
@@ -13,25 +13,56 @@
 use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, Params, ParseError};
+use derive_aoc_runner::Params;
 
 #[derive(Default, Clone)]
-pub struct Day17(String);
+pub struct Day17(String, Parameters);
+
+/// The numbers of falling rocks
+#[derive(Debug, Clone, Params)]
+pub struct Parameters {
+    /// The number of rocks in part 1
+    rocks: usize,
+    /// The number of rocks in part 2
+    many_rocks: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters {
+            rocks: 2022,
+            many_rocks: 1000000000000,
+        }
+    }
+}
 
 impl Day for Day17 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("3068"),
+        part2: Some("1514285714288"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input.to_owned();
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
-        self.play_for_n_rounds(2022)
+        self.play_for_n_rounds(self.1.rocks)
     }
 
     fn part2(&mut self) -> Self::Result2 {
-        self.play_for_n_rounds(1000000000000)
+        self.play_for_n_rounds(self.1.many_rocks)
+    }
+
+    fn params(&mut self) -> Option<&mut dyn Params> {
+        Some(&mut self.1)
     }
 }
 
@@ -124,7 +155,6 @@ impl Day17 {
                     for pos in rock.iter_mut() {
                         pos.1 += 1;
                     }
-                } else {
                 }
 
                 jet_idx += 1;
@@ -159,23 +189,24 @@ impl Day17 {
     }
 }
 
+/// The example input from the puzzle's description
+const EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
 #[cfg(test)]
 mod test {
     use super::*;
 
-    const INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
-
     #[test]
     fn part_1() {
         let mut day = Day17::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 3068);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day17::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 1514285714288);
     }
 }
@@ -8,7 +8,9 @@
 
 use rustc_hash::FxHashSet as HashSet;
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
+
+use crate::common::parser::parse_at;
 
 type Cube = (i8, i8, i8);
 
@@ -19,7 +21,14 @@ impl Day for Day18 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("64"),
+        part2: Some("58"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         let mut min_x: Option<i8> = None;
         let mut max_x: Option<i8> = None;
         let mut min_y: Option<i8> = None;
@@ -30,10 +39,14 @@ impl Day for Day18 {
         self.0 = input
             .lines()
             .map(|line| {
-                let mut nums = line.split(',').map(|num| num.parse().unwrap());
-                let x = nums.next().unwrap();
-                let y = nums.next().unwrap();
-                let z = nums.next().unwrap();
+                let (x, y, z) = match line.split(',').collect::<Vec<&str>>()[..] {
+                    [x, y, z] => (
+                        parse_at(input, x, "number")?,
+                        parse_at(input, y, "number")?,
+                        parse_at(input, z, "number")?,
+                    ),
+                    _ => return Err(ParseError::at(input, line, "coordinates 'x,y,z'")),
+                };
 
                 min_x = Some(min_x.unwrap_or(x).min(x));
                 max_x = Some(max_x.unwrap_or(x).max(x));
@@ -42,14 +55,19 @@ impl Day for Day18 {
                 min_z = Some(min_z.unwrap_or(z).min(z));
                 max_z = Some(max_z.unwrap_or(z).max(z));
 
-                (x, y, z)
+                Ok((x, y, z))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
+
+        if self.0.is_empty() {
+            return Err(ParseError::new(1, 1, "at least one cube"));
+        }
 
         self.1 = (
             (min_x.unwrap(), min_y.unwrap(), min_z.unwrap()),
             (max_x.unwrap(), max_y.unwrap(), max_z.unwrap()),
         );
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -108,11 +126,8 @@ impl Day for Day18 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "2,2,2
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "2,2,2
 1,2,2
 3,2,2
 2,1,2
@@ -126,17 +141,21 @@ mod test {
 2,1,5
 2,3,5";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day18::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 64);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day18::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 58);
     }
 }
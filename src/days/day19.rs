@@ -4,7 +4,9 @@
 //! number of 'geode' on can produce in 24 / 32 minutes.
 //!
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
+
+use crate::common::parser::parse_at;
 
 type Time = u16;
 // Using u16 instead of usize seems to get a speedup of ~ 3x
@@ -50,30 +52,36 @@ impl Day for Day19 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("33"),
+        part2: Some("3472"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input
             .lines()
             .map(|line| {
                 let mut words = line.split(' ');
-                let ore = words.nth(6).unwrap().parse().unwrap();
-                let clay = words.nth(5).unwrap().parse().unwrap();
-                let obsidian = (
-                    words.nth(5).unwrap().parse().unwrap(),
-                    words.nth(2).unwrap().parse().unwrap(),
-                );
-                let geode = (
-                    words.nth(5).unwrap().parse().unwrap(),
-                    words.nth(2).unwrap().parse().unwrap(),
-                );
-
-                Blueprint {
+                let mut next_cost = |nth: usize| {
+                    let word = words.nth(nth).unwrap_or(&line[line.len()..]);
+                    parse_at(input, word, "cost")
+                };
+                let ore = next_cost(6)?;
+                let clay = next_cost(5)?;
+                let obsidian = (next_cost(5)?, next_cost(2)?);
+                let geode = (next_cost(5)?, next_cost(2)?);
+
+                Ok(Blueprint {
                     ore_cost: ore,
                     clay_cost: clay,
                     obsidian_cost: obsidian,
                     geode_cost: geode,
-                }
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -261,25 +269,26 @@ fn search(blueprint: &Blueprint, start: BlueprintResult, max_time: Time) -> Blue
     best_score
 }
 
+/// The example input from the puzzle's description
+const EXAMPLE: &str =
+"Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
 #[cfg(test)]
 mod test {
     use super::*;
 
-    const INPUT: &str =
-"Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
-Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
-
     #[test]
     fn part_1() {
         let mut day = Day19::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 33);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day19::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 56 * 62);
     }
 }
@@ -7,29 +7,54 @@
 //!
 //! calculate a sum of three special values.
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, Params, ParseError};
+use derive_aoc_runner::Params;
+
+use crate::common::parser::parse_at;
 
 type Number = i64;
 
 #[derive(Default, Clone)]
-pub struct Day20(Vec<Number>, usize);
+pub struct Day20(Vec<Number>, usize, Parameters);
+
+#[derive(Debug, Clone, Params)]
+pub struct Parameters {
+    /// The magic number to multiply all numbers by in part 2
+    decryption_key: u32,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters {
+            decryption_key: 811589153,
+        }
+    }
+}
 
 impl Day for Day20 {
     type Result1 = i64;
     type Result2 = i64;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("3"),
+        part2: Some("1623178306"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input
             .lines()
             .enumerate()
             .map(|(idx, part)| {
-                let num = part.parse().unwrap();
+                let num = parse_at(input, part, "number")?;
                 if num == 0 {
                     self.1 = idx;
                 }
-                num
+                Ok(num)
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -46,12 +71,11 @@ impl Day for Day20 {
     }
 
     fn part2(&mut self) -> Self::Result2 {
-        const DECRYPTION_KEY: u32 = 811589153;
-
+        let decryption_key = self.2.decryption_key;
         let nums = shuffle(
             self.0.as_slice(),
             self.0.len(),
-            DECRYPTION_KEY % (self.0.len() as u32 - 1),
+            decryption_key % (self.0.len() as u32 - 1),
             10,
         );
         let new_zero_idx = nums
@@ -62,7 +86,11 @@ impl Day for Day20 {
             .into_iter()
             .map(|i| *nums[(new_zero_idx + i) % self.0.len()])
             .sum();
-        DECRYPTION_KEY as Self::Result2 * total
+        decryption_key as Self::Result2 * total
+    }
+
+    fn params(&mut self) -> Option<&mut dyn Params> {
+        Some(&mut self.2)
     }
 }
 
@@ -90,11 +118,8 @@ fn shuffle(nums: &[Number], len: usize, factor: u32, rounds: usize) -> Vec<&Numb
     ref_vec
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "1
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "1
 2
 -3
 3
@@ -102,17 +127,21 @@ mod test {
 0
 4";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day20::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 3);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day20::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 1623178306);
     }
 }
@@ -6,9 +6,11 @@
 //! - b) treat 'root: a x b' as 'a == b' and solve for variable 'humn'
 //!
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+use crate::common::parser::parse_at;
+
 type Number = i64;
 
 #[derive(Debug, Clone)]
@@ -17,6 +19,13 @@ enum Monkey {
     Calculation(String, String, char, fn(Number, Number) -> Number),
 }
 
+/// Parse a monkey's name, given as '<name>:'
+fn parse_name(input: &str, word: &str) -> Result<String, ParseError> {
+    word.strip_suffix(':')
+        .map(str::to_string)
+        .ok_or_else(|| ParseError::at(input, word, "'<name>:'"))
+}
+
 #[derive(Default, Clone)]
 pub struct Day21(HashMap<String, Monkey>);
 
@@ -24,33 +33,43 @@ impl Day for Day21 {
     type Result1 = Number;
     type Result2 = Number;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("152"),
+        part2: Some("301"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input
             .lines()
-            .map(|line| {
-                let mut words = line.split(' ');
-                let name = words.next().and_then(|w| w.strip_suffix(':')).unwrap();
-                let a = words.next().unwrap();
-                if a.chars().next().unwrap().is_numeric() {
-                    (name.to_string(), Monkey::Number(a.parse().unwrap()))
-                } else {
-                    let op = words.next().unwrap();
-                    let b = words.next().unwrap();
-                    let op_name = op.chars().next().unwrap();
-                    let op = match op {
-                        "+" => std::ops::Add::add,
-                        "-" => std::ops::Sub::sub,
-                        "*" => std::ops::Mul::mul,
-                        "/" => std::ops::Div::div,
-                        _ => panic!("Unknown operator: {}", op),
+            .map(|line| match line.split(' ').collect::<Vec<&str>>()[..] {
+                [name, a] => {
+                    let name = parse_name(input, name)?;
+                    Ok((name, Monkey::Number(parse_at(input, a, "number")?)))
+                }
+                [name, a, op, b] => {
+                    let name = parse_name(input, name)?;
+                    let (op_name, op): (char, fn(Number, Number) -> Number) = match op {
+                        "+" => ('+', std::ops::Add::add),
+                        "-" => ('-', std::ops::Sub::sub),
+                        "*" => ('*', std::ops::Mul::mul),
+                        "/" => ('/', std::ops::Div::div),
+                        _ => return Err(ParseError::at(input, op, "'+', '-', '*' or '/'")),
                     };
-                    (
-                        name.to_string(),
+                    Ok((
+                        name,
                         Monkey::Calculation(a.to_string(), b.to_string(), op_name, op),
-                    )
+                    ))
                 }
+                _ => Err(ParseError::at(
+                    input,
+                    line,
+                    "'<name>: <number>' or '<name>: <name> <op> <name>'",
+                )),
             })
-            .collect()
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -159,11 +178,8 @@ fn trace_for(
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "root: pppw + sjmn
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "root: pppw + sjmn
 dbpl: 5
 cczh: sllz + lgvd
 zczc: 2
@@ -179,17 +195,21 @@ lgvd: ljgn * ptdq
 drzm: hmdt - zczc
 hmdt: 32";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day21::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 152);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day21::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 301);
     }
 }
@@ -9,7 +9,8 @@
 
 use std::{collections::VecDeque, ops::ControlFlow};
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, Params, ParseError};
+use derive_aoc_runner::Params;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 #[derive(Clone, PartialEq, Eq)]
@@ -23,6 +24,7 @@ type Coord = (C, C);
 type Coord3 = (C, C, C);
 type Board = HashMap<Coord, Tile>;
 
+#[allow(dead_code)]
 #[cfg(debug_assertions)]
 fn print_board(board: &Board) {
     let max_x = board.keys().map(|(_, x)| *x).max().unwrap();
@@ -56,15 +58,37 @@ pub struct Day22 {
     board: Board,
     start: Coord,
     commands: Vec<Command>,
+    params: Parameters,
+}
+
+/// The side length of the cube's faces, which is much smaller in the example
+#[derive(Debug, Clone, Params)]
+pub struct Parameters {
+    side_length: C,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters { side_length: 50 }
+    }
 }
 
 impl Day for Day22 {
     type Result1 = i32;
     type Result2 = i32;
 
-    fn parse(&mut self, input: &str) {
-        const SIDE_LENGTH: C = 50;
-        let (board, commands) = input.split_once("\n\n").unwrap();
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("6032"),
+        part2: Some("5031"),
+        params: &[("side_length", "4")],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        let side_length = self.params.side_length;
+        let (board, commands) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at(input, &input[input.len()..], "empty line"))?;
         self.board = board
             .lines()
             .enumerate()
@@ -81,15 +105,15 @@ impl Day for Day22 {
 
         self.start = (
             0,
-            (0..SIDE_LENGTH * 6)
+            (0..side_length * 6)
                 .find(|i| self.board.contains_key(&(0, *i)))
-                .unwrap(),
+                .ok_or_else(|| ParseError::new(1, 1, "open tile in first row"))?,
         );
 
         let commands_vec = {
             let mut vec = vec![];
             let mut num = 0;
-            for c in commands.chars() {
+            for (idx, c) in commands.char_indices() {
                 if c == 'R' {
                     if num != 0 {
                         vec.push(Command::Walk(num as u8));
@@ -103,7 +127,10 @@ impl Day for Day22 {
                     }
                     vec.push(Command::TurnL());
                 } else {
-                    num = num * 10 + c.to_digit(10).unwrap();
+                    let digit = c.to_digit(10).ok_or_else(|| {
+                        ParseError::at(input, &commands[idx..], "'R', 'L' or digit")
+                    })?;
+                    num = num * 10 + digit;
                 }
             }
 
@@ -115,14 +142,14 @@ impl Day for Day22 {
         };
 
         self.commands = commands_vec;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
-        const SIDE_LENGTH: i16 = 50;
         let direction_right = (0, 1);
         let ((row, col), dir) = walk_board(
             &self.board,
-            SIDE_LENGTH,
+            self.params.side_length,
             self.start,
             direction_right,
             &self.commands,
@@ -139,11 +166,10 @@ impl Day for Day22 {
     }
 
     fn part2(&mut self) -> Self::Result2 {
-        const SIDE_LENGTH: i16 = 50;
         let direction_right = (0, 1);
         let ((row, col), dir) = walk_cube(
             &self.board,
-            SIDE_LENGTH,
+            self.params.side_length,
             self.start,
             direction_right,
             &self.commands,
@@ -158,6 +184,10 @@ impl Day for Day22 {
                 _ => unreachable!(),
             }
     }
+
+    fn params(&mut self) -> Option<&mut dyn Params> {
+        Some(&mut self.params)
+    }
 }
 
 /// Callback to handle walking past an edge
@@ -320,11 +350,8 @@ fn find_point_on_cube(board: &Board, side_length: C, current: Coord, target: Coo
     panic!("Did not find point around edge")
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "        ...#
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "        ...#
         .#..
         #...
         ....
@@ -339,19 +366,36 @@ mod test {
 
 10R5L5R10L4R5L5";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_runner::input::normalize;
+
     #[test]
     fn part_1() {
         let mut day = Day22::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         let expected = walk_board(&day.board, 4, day.start, (0, 1), &day.commands);
         assert_eq!(expected, ((5, 7), (0, 1)));
+        day.params.side_length = 4;
+        assert_eq!(day.part1(), 6032);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day22::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         let expected = walk_cube(&day.board, 4, day.start, (0, 1), &day.commands);
         assert_eq!(expected, ((4, 6), (-1, 0)));
+        day.params.side_length = 4;
+        assert_eq!(day.part2(), 5031);
+    }
+
+    #[test]
+    fn parse_newline_terminated_input() {
+        let input = format!("{}\n", EXAMPLE);
+        let mut day = Day22::default();
+        day.parse(&normalize(&input, Day22::TRIM_INPUT)).unwrap();
+        assert_eq!(day.commands.len(), 13);
     }
 }
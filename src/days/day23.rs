@@ -6,7 +6,7 @@
 //! - b) count number of rounds until fixpointn is reached
 //!
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 type C = i32;
@@ -19,7 +19,14 @@ impl Day for Day23 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("110"),
+        part2: Some("20"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         self.0 = input
             .lines()
             .enumerate()
@@ -33,6 +40,7 @@ impl Day for Day23 {
                 })
             })
             .collect();
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -158,6 +166,7 @@ fn get_min_max(map: &HashSet<Coord>) -> (C, C, C, C) {
     (min_x, max_x, min_y, max_y)
 }
 
+#[allow(dead_code)]
 #[cfg(debug_assertions)]
 fn print_map(map: &HashSet<Coord>) {
     let (min_x, max_x, min_y, max_y) = get_min_max(map);
@@ -170,11 +179,8 @@ fn print_map(map: &HashSet<Coord>) {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "..............
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "..............
 ..............
 .......#......
 .....###.#....
@@ -187,17 +193,21 @@ mod test {
 ..............
 ..............";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day23::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 110);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day23::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 20);
     }
 }
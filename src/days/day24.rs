@@ -7,7 +7,7 @@
 
 use std::collections::VecDeque;
 
-use aoc_runner::Day;
+use aoc_runner::{Day, Example, ParseError};
 use rustc_hash::{FxHashMap, FxHashSet as HashSet};
 
 type C = i32;
@@ -25,13 +25,35 @@ impl Day for Day24 {
     type Result1 = usize;
     type Result2 = usize;
 
-    fn parse(&mut self, input: &str) {
-        self.col_length = input.lines().count() - 2;
-        self.row_length = input.lines().next().unwrap().len() - 2;
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("18"),
+        part2: Some("54"),
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.col_length = input
+            .lines()
+            .count()
+            .checked_sub(2)
+            .ok_or_else(|| ParseError::at(input, &input[input.len()..], "surrounding walls"))?;
+        self.row_length = input
+            .lines()
+            .next()
+            .and_then(|line| line.len().checked_sub(2))
+            .ok_or_else(|| ParseError::new(1, 1, "surrounding walls"))?;
         self.rows = vec![vec![]; self.col_length + 2];
         self.cols = vec![vec![]; self.row_length + 2];
 
-        input.lines().enumerate().for_each(|(y, line)| {
+        for (y, line) in input.lines().enumerate() {
+            if line.len() != self.row_length + 2 {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("row of length {}", self.row_length + 2),
+                ));
+            }
             line.chars().enumerate().for_each(|(x, ch)| {
                 match ch {
                     '>' => {
@@ -49,7 +71,9 @@ impl Day for Day24 {
                     _ => {}
                 };
             });
-        });
+        }
+
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -139,6 +163,7 @@ impl Day24 {
         row_blizz || col_blizz
     }
 
+    #[allow(dead_code)]
     #[cfg(debug_assertions)]
     fn print_map(&self, time: usize, pos: Coord, start: Coord, target: Coord) {
         for y in 0..self.col_length as C + 2 {
@@ -171,28 +196,29 @@ impl Day24 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "#.######
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "#.######
 #>>.<^<#
 #.<..<<#
 #>v.><>#
 #<^v^^>#
 ######.#";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day24::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1(), 18);
     }
 
     #[test]
     fn part_2() {
         let mut day = Day24::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part2(), 54);
     }
 }
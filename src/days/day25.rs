@@ -4,27 +4,28 @@
 
 use std::fmt::Display;
 
-use aoc_runner::Day;
+use aoc_runner::{Answer, Day, Example, ParseError};
 
 #[derive(Clone)]
 pub struct Snafu(Vec<i8>);
 
 impl Snafu {
-    fn from_str(s: &str) -> Self {
+    /// Parse a SNAFU number or return the byte offset of the first non-SNAFU character
+    fn from_str(s: &str) -> Result<Self, usize> {
         let values = s
-            .chars()
-            .map(|c| match c {
-                '0' => 0,
-                '1' => 1,
-                '2' => 2,
-                '-' => -1,
-                '=' => -2,
-                _ => panic!("Read non-SNAFU character {}", c),
+            .char_indices()
+            .map(|(idx, c)| match c {
+                '0' => Ok(0),
+                '1' => Ok(1),
+                '2' => Ok(2),
+                '-' => Ok(-1),
+                '=' => Ok(-2),
+                _ => Err(idx),
             })
             .rev()
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Self(values)
+        Ok(Self(values))
     }
 
     fn add(a: Self, b: Self) -> Self {
@@ -92,6 +93,12 @@ impl Display for Snafu {
     }
 }
 
+impl Answer for Snafu {
+    fn answer(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl std::fmt::Debug for Snafu {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         Display::fmt(&self, f)
@@ -105,8 +112,22 @@ impl Day for Day25 {
     type Result1 = Snafu;
     type Result2 = String;
 
-    fn parse(&mut self, input: &str) {
-        self.0 = input.lines().map(Snafu::from_str).collect();
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: EXAMPLE,
+        part1: Some("2=-1=0"),
+        part2: None,
+        params: &[],
+    });
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.0 = input
+            .lines()
+            .map(|line| {
+                Snafu::from_str(line)
+                    .map_err(|idx| ParseError::at(input, &line[idx..], "'0', '1', '2', '-' or '='"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn part1(&mut self) -> Self::Result1 {
@@ -118,11 +139,8 @@ impl Day for Day25 {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const INPUT: &str = "1=-0-2
+/// The example input from the puzzle's description
+const EXAMPLE: &str = "1=-0-2
 12111
 2=0=
 21
@@ -136,10 +154,14 @@ mod test {
 1=
 122";
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn part_1() {
         let mut day = Day25::default();
-        day.parse(INPUT);
+        day.parse(EXAMPLE).unwrap();
         assert_eq!(day.part1().to_string(), "2=-1=0");
     }
 }
@@ -1,6 +1,8 @@
 //! Collection of all puzzles and utilities to run a day's puzzle
 
-// pub(crate) mod day00; // only used as example; no valid module
+/// Template for new days, see the `new-day` command
+#[cfg(test)]
+mod day00;
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -27,10 +29,11 @@ pub mod day23;
 pub mod day24;
 pub mod day25;
 
-use aoc_runner::{Analyzer, Day};
+use aoc_runner::Analyzer;
 use derive_aoc_runner::{Analyzer, AoC};
 
 #[derive(Analyzer, AoC)]
+#[aoc(year = 2022)]
 pub struct Days(
     day01::Day01,
     day02::Day02,
@@ -1,2 +1,34 @@
+//! Solutions to [Advent of Code 2022](https://adventofcode.com/2022)
+//!
+//! Besides the `aoc2022` binary, the solutions can be embedded with [solve], which returns a
+//! part's answer instead of printing it.
+
 pub mod common;
 pub mod days;
+
+pub use aoc_runner::{ParamValues, SolveError as Error};
+
+use aoc_runner::Year;
+use days::Days;
+
+/// Solves a part (1 or 2) of a day on `input` and returns its answer, as printed by the runner
+///
+/// Nothing is printed; invalid days and parts, parse errors and panics are returned as [Error].
+///
+/// ```
+/// assert_eq!(aoc2022::solve(1, 1, "1000\n2000\n\n4000").unwrap(), "4000");
+/// assert!(aoc2022::solve(26, 1, "").is_err());
+/// ```
+pub fn solve(day: usize, part: usize, input: &str) -> Result<String, Error> {
+    solve_with_params(day, part, input, &ParamValues::new())
+}
+
+/// Like [solve], but with parameter values for the day, see [aoc_runner::Day::params]
+pub fn solve_with_params(
+    day: usize,
+    part: usize,
+    input: &str,
+    params: &ParamValues,
+) -> Result<String, Error> {
+    aoc_runner::solve(&<Days as Year>::days(), day, part, input, params)
+}
@@ -1,46 +1,256 @@
-use std::process::exit;
+use std::{path::Path, process::exit};
 
 use aoc2022::days::*;
+use aoc_runner::alloc::CountingAllocator;
+use aoc_runner::year::input_dir;
+use aoc_runner::{
+    cache, Answers, Baseline, Cache, Format, LogAnalyzer, OutputAnalyzer, ParallelAnalyzer,
+    ParamValues, Results, TimeAnalyzer, TraceAnalyzer, Verdict, Year,
+};
+use cli::{Command, Input, Options};
+
+mod cli;
+mod scaffold;
+
+/// Counts allocations for `--allocations`, but only once they are enabled
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// All registered years, the latest one is selected unless `--year` is given
+const YEARS: [usize; 1] = [Days::YEAR];
 
 fn main() {
-    let args = std::env::args().collect::<Vec<String>>();
-    if args.contains(&"--help".to_owned()) || args.contains(&"-h".to_owned()) {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    let year = cli::parse_year(&args, &YEARS).unwrap_or_else(|err| fail(err));
+    match year {
+        Days::YEAR => {
+            let days = Days::new();
+            let analyzer = days.get_analyzer();
+            run(days, analyzer, &args)
+        }
+        _ => unreachable!("parse_year only selects registered years"),
+    }
+}
+
+/// Runs the command given by `args` on a year's days
+fn run<Y: Year>(mut days: Y, analyzer: TimeAnalyzer, args: &[String]) {
+    let available = Y::days().iter().map(|info| info.day).collect::<Vec<_>>();
+    let options = Options::parse(args, Y::YEAR, &available).unwrap_or_else(|err| fail(err));
+    if options.help {
         usage();
         exit(0);
     }
 
-    let mut days = Days::new();
-    let (day, part) = get_args();
-    if let Some(day) = day {
-        let input = std::io::stdin()
-            .lines()
-            .into_iter()
-            .map(|line| line.unwrap())
-            .collect::<Vec<String>>()
-            .join("\n");
+    if options.command == Command::NewDay {
+        let files = scaffold::new_day(options.days[0], &input_dir(".", options.year))
+            .unwrap_or_else(|err| fail(err));
+        for file in files {
+            println!("{}", file.display());
+        }
+        return;
+    }
 
-        days.run_part(day, part, &input, &mut days.get_analyzer());
+    if options.command == Command::List {
+        list(&days, &options);
+        return;
+    }
+
+    let (inputs, answers, mut params) = if options.input == Input::Example {
+        let (inputs, answers, params) = examples(&days, &options);
+        (inputs, Some(answers), params)
     } else {
-        let inputs = (1..=days.len())
+        let answers = (options.command == Command::Check)
+            .then(|| read_answers(&options.answers).unwrap_or_else(|err| fail(err)));
+        let inputs = options.read_inputs().unwrap_or_else(|err| fail(err));
+        let params = read_params(&options).unwrap_or_else(|err| fail(err));
+        (inputs, answers, params)
+    };
+    params.extend(options.params.clone());
+    days.set_params(&params).unwrap_or_else(|err| fail(err));
+
+    let mut analyzer = options
+        .exports
+        .iter()
+        .cloned()
+        .fold(analyzer, |analyzer, (path, format)| {
+            analyzer.export_to(path, format)
+        });
+    if let Some(path) = &options.baseline {
+        let baseline = read_baseline(path).unwrap_or_else(|err| fail(err));
+        analyzer = analyzer.compare_to(baseline, options.threshold);
+    }
+    if options.format != Format::Human {
+        analyzer = analyzer.quiet();
+    }
+    if options.allocations {
+        analyzer = analyzer.count_allocations();
+    }
+    let output = OutputAnalyzer::new(options.format);
+    let cache = load_cache(&options);
+    let cache = cache.as_ref();
+    let log = options.log.then(LogAnalyzer::new);
+    let trace = options.trace.as_ref().map(TraceAnalyzer::new);
+    let mut analyzers = (analyzer, output, log, trace);
+    let results = execute(&mut days, &mut analyzers, &options, &inputs, cache);
+    let regressions = analyzers.0.regressions();
+    let failed = analyzers.0.failed();
+    if let Some(Err(err)) = cache.map(Cache::save) {
+        eprintln!("Could not write the answer cache: {}", err);
+    }
+
+    let mut success = regressions.is_empty() && !failed;
+    if let Some(answers) = answers {
+        let parts = options
+            .parts()
             .into_iter()
-            .map(|idx| {
-                let input_file_path = format!("./input/{:0>2}.txt", idx);
-                std::fs::read_to_string(input_file_path).expect("Could not read input file")
-            })
-            .collect::<Vec<String>>();
-        days.run_all(&inputs[..]);
+            .filter(|(day, _)| inputs.iter().any(|(input_day, _)| input_day == day))
+            .collect::<Vec<_>>();
+        success &= verify(&answers, &results, &parts, options.format);
+    }
+    if !success {
+        exit(1);
+    }
+}
+
+/// Prints an error and exits with the exit code for invalid usage
+fn fail(err: impl std::fmt::Display) -> ! {
+    eprintln!("error: {}", err);
+    eprintln!("Run with --help for usage");
+    exit(2);
+}
+
+/// Runs the selected days, repeatedly if `runs` is given or in parallel if `threads` is given
+fn execute<Y: Year>(
+    days: &mut Y,
+    analyzer: &mut impl ParallelAnalyzer,
+    options: &Options,
+    inputs: &[(usize, String)],
+    cache: Option<&Cache>,
+) -> Results {
+    let part = options.part;
+    match (options.runs, options.threads) {
+        (Some(runs), _) => days.run_days_repeated(inputs, part, analyzer, runs),
+        (None, Some(threads)) => days.run_days_parallel(inputs, part, analyzer, threads, cache),
+        (None, None) => days.run_days(inputs, part, analyzer, options.timeout, cache),
+    }
+}
+
+/// Loads the answer cache, if it is enabled
+fn load_cache(options: &Options) -> Option<Cache> {
+    let path = options.cache.as_ref()?;
+    match cache::build_id() {
+        Ok(build_id) => Some(Cache::load(path, build_id)),
+        Err(err) => {
+            eprintln!(
+                "Not caching answers, the binary could not be identified: {}",
+                err
+            );
+            None
+        }
     }
 }
 
-fn get_args() -> (Option<usize>, Option<usize>) {
-    let args = std::env::args().collect::<Vec<String>>();
-    let day = args
-        .get(1)
-        .map(|arg| arg.parse().expect("'day' must be a number"));
-    let part = args
-        .get(2)
-        .map(|arg| arg.parse().expect("'part' must be a number"));
-    (day, part)
+/// Collects the example inputs, answers and parameters of the selected days, skipping days without
+/// an example
+fn examples(days: &impl Year, options: &Options) -> (Vec<(usize, String)>, Answers, ParamValues) {
+    let mut inputs = vec![];
+    let mut answers = Answers::new();
+    let mut params = ParamValues::new();
+    for &day in options.days.iter() {
+        let Some(example) = days.example(day) else {
+            eprintln!("Day {} has no example, skipping it", day);
+            continue;
+        };
+        inputs.push((day, example.input.to_owned()));
+        for (part, answer) in [(1, example.part1), (2, example.part2)] {
+            if let Some(answer) = answer {
+                answers.insert(day, part, answer);
+            }
+        }
+        for (key, value) in example.params {
+            params.insert(day, *key, *value);
+        }
+    }
+    (inputs, answers, params)
+}
+
+fn read_answers(path: &Path) -> Result<Answers, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| format!("could not read answers file '{}': {}", path.display(), err))?;
+    Answers::parse(&content)
+        .map_err(|err| format!("invalid answers file '{}': {}", path.display(), err))
+}
+
+/// Reads the parameters file, which is optional unless it was given by `--params`
+fn read_params(options: &Options) -> Result<ParamValues, String> {
+    let path = &options.params_file;
+    if !options.params_given && !path.is_file() {
+        return Ok(ParamValues::new());
+    }
+    let content = std::fs::read_to_string(path).map_err(|err| {
+        format!(
+            "could not read parameters file '{}': {}",
+            path.display(),
+            err
+        )
+    })?;
+    ParamValues::parse(&content)
+        .map_err(|err| format!("invalid parameters file '{}': {}", path.display(), err))
+}
+
+fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| format!("could not read baseline file '{}': {}", path.display(), err))?;
+    Baseline::parse(&content)
+        .map_err(|err| format!("invalid baseline file '{}': {}", path.display(), err))
+}
+
+/// Prints the selected days with their input files, number of known answers and whether they
+/// have an example
+fn list(days: &impl Year, options: &Options) {
+    let answers = read_answers(&options.answers).ok();
+    for &day in options.days.iter() {
+        let input = match options.input_file(day) {
+            Some(path) if path.is_file() => path.display().to_string(),
+            Some(path) => format!("missing ({})", path.display()),
+            None if options.input == Input::Example => "example".to_owned(),
+            None => "stdin".to_owned(),
+        };
+        let known = answers.as_ref().map_or(0, |answers| {
+            (1..=2)
+                .filter(|&part| answers.get(day, part).is_some())
+                .count()
+        });
+        let example = if days.example(day).is_some() {
+            "yes"
+        } else {
+            "no"
+        };
+        println!(
+            "Day {:>2} | input: {} | answers: {}/2 | example: {}",
+            day, input, known, example
+        );
+    }
+}
+
+/// Prints a verdict for every part and returns false if any part failed
+///
+/// Verdicts go to stderr unless the results are printed in the [Format::Human] format.
+fn verify(answers: &Answers, results: &Results, parts: &[(usize, usize)], format: Format) -> bool {
+    let print = |line: String| match format {
+        Format::Human => println!("{}", line),
+        Format::Plain | Format::Json => eprintln!("{}", line),
+    };
+    print(String::new());
+    print("Verification".to_owned());
+    let mut success = true;
+    for &(day, part) in parts {
+        let actual = results.get(&(day, part)).map(String::as_str);
+        let verdict = answers.verify(day, part, actual);
+        print(format!(" - Day {:>2} Part {}: {}", day, part, verdict));
+        success &= !matches!(verdict, Verdict::Fail { .. });
+    }
+    success
 }
 
 fn usage() {
@@ -52,5 +262,71 @@ fn usage() {
             Some(name_str.to_string())
         })
         .unwrap_or_else(|| String::from("<binary>"));
-    println!("USAGE: {} [day] [part]", binary_name);
+    println!(
+        "USAGE: {} [run|check|list|bench|new-day] [days] [part] [options]
+
+COMMANDS:
+    run      Run the selected days (default)
+    check    Run the selected days and verify their results against the answers file
+    list     List the selected days with their input files and known answers
+    bench    Run the selected days {} times (or --runs) and show timing statistics
+    new-day  Create src/days/dayNN.rs from the template, register it in src/days/mod.rs and
+             create an empty input file, e.g. 'new-day 5'
+
+ARGUMENTS:
+    days     Days to select, e.g. 5 or 1-5,12,20- (default: all days)
+    part     Part to run, 1 or 2 (default: both parts)
+
+OPTIONS:
+    --year <year>                         Year to run, one of {} (default: {})
+    --input <file|dir|->                  Input directory with NN.txt files, a single day's input
+                                          file or - for stdin (default: ./input/<year> if it
+                                          exists, ./input otherwise)
+    --answers <file>                      Expected answers for 'check' (default: {} in
+                                          the input directory)
+    --example                             Run on the puzzles' example inputs and verify their
+                                          answers
+    --param <day>.<name>=<value>          Set a day's parameter, e.g. 15.row=10, can be given
+                                          multiple times
+    --params <file>                       Parameters of days, ignored with --example (default: {}
+                                          in the input directory, if it exists)
+    --format <human|plain|json>           Print results for humans (default), only the answers or
+                                          as JSON lines with day, part, answer and duration_ns;
+                                          plain and json print no timings
+    --export <file>.{{json,csv,md}}         Write timings to a file, can be given multiple times
+    --save-baseline[=<file>]              Store timings as a baseline (default: {} in
+                                          the input directory)
+    --baseline[=<file>]                   Compare timings with a baseline and exit with a non-zero
+                                          exit code on regressions (default: {} in the input
+                                          directory)
+    --threshold <percent>                 Percentage a step may get slower than its baseline
+                                          (default: {})
+    --parallel[=<threads>]                Run days in parallel
+    --runs <n>                            Run every day n times on fresh instances
+    --timeout <seconds>                   Stop waiting for parts that run longer than this
+    --log                                 Log every step to stderr
+    --trace <file>                        Write a trace of the run in the Chrome Trace Event
+                                          Format, e.g. for chrome://tracing or ui.perfetto.dev
+    --allocations                         Show allocations, allocated bytes and peak live bytes of
+                                          every day in the timings table
+    --cache                               Take the answers of parts whose input did not change
+                                          since the last run of this build from
+                                          {}/<year>.csv instead of solving them again
+    --no-cache                            Solve all parts, overriding an earlier --cache
+    -h, --help                            Print this help",
+        binary_name,
+        cli::BENCH_RUNS,
+        YEARS
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+        YEARS.iter().max().unwrap(),
+        aoc_runner::testing::ANSWERS_FILE,
+        cli::PARAMS_FILE,
+        cli::BASELINE_FILE,
+        cli::BASELINE_FILE,
+        cli::THRESHOLD,
+        cli::CACHE_DIR,
+    );
 }
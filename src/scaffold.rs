@@ -0,0 +1,137 @@
+//! Scaffolding for new days, based on the template `src/days/day00.rs`
+
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("days/day00.rs");
+const DAYS_DIR: &str = "./src/days";
+
+/// Creates `src/days/dayNN.rs`, registers it in `src/days/mod.rs` and creates an empty input file
+/// in `input_dir`, returning all created or changed files
+///
+/// Must be run from the repository's root.
+pub fn new_day(day: usize, input_dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let days_dir = Path::new(DAYS_DIR);
+    let mod_path = days_dir.join("mod.rs");
+    let day_path = days_dir.join(format!("day{:0>2}.rs", day));
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).map_err(|err| {
+            format!(
+                "could not read '{}': {} (run this from the repository's root)",
+                path.display(),
+                err
+            )
+        })
+    };
+    let write = |path: &Path, content: &str| {
+        std::fs::write(path, content)
+            .map_err(|err| format!("could not write '{}': {}", path.display(), err))
+    };
+
+    if day_path.exists() {
+        return Err(format!("'{}' already exists", day_path.display()));
+    }
+    let mod_rs = register(&read(&mod_path)?, day)?;
+
+    write(&day_path, &render(day))?;
+    write(&mod_path, &mod_rs)?;
+    let mut files = vec![day_path, mod_path];
+
+    std::fs::create_dir_all(input_dir)
+        .map_err(|err| format!("could not create '{}': {}", input_dir.display(), err))?;
+    let input_path = input_dir.join(format!("{:0>2}.txt", day));
+    if !input_path.exists() {
+        write(&input_path, "")?;
+        files.push(input_path);
+    }
+    Ok(files)
+}
+
+/// The template with all names changed to the given day
+fn render(day: usize) -> String {
+    TEMPLATE
+        .replace("Day00", &format!("Day{:0>2}", day))
+        .replace("Day 00", &format!("Day {:0>2}", day))
+}
+
+/// Adds the day's module and its field in the `Days` struct to the content of `src/days/mod.rs`
+///
+/// The field is appended to `Days`, with an `#[aoc(day = N)]` attribute unless its position
+/// already matches its day.
+fn register(mod_rs: &str, day: usize) -> Result<String, String> {
+    let start = mod_rs
+        .find("pub struct Days(")
+        .ok_or_else(|| "could not find 'pub struct Days(' in src/days/mod.rs".to_owned())?;
+    let end = start
+        + mod_rs[start..]
+            .find(");")
+            .ok_or_else(|| "could not find the end of 'Days' in src/days/mod.rs".to_owned())?;
+    let fields = mod_rs[start..end].matches("::").count();
+    if mod_rs[start..end].contains(&format!("day{:0>2}::", day)) {
+        return Err(format!("day {} is already registered in 'Days'", day));
+    }
+    let attribute = match day == fields + 1 {
+        true => String::new(),
+        false => format!("    #[aoc(day = {})]\n", day),
+    };
+
+    let last_mod = mod_rs
+        .rfind("pub mod day")
+        .ok_or_else(|| "could not find any 'pub mod dayNN;' in src/days/mod.rs".to_owned())?;
+    let after_last_mod = last_mod + mod_rs[last_mod..].find('\n').unwrap_or(0) + 1;
+
+    Ok(format!(
+        "{}pub mod day{:0>2};\n{}{}    day{:0>2}::Day{:0>2},\n{}",
+        &mod_rs[..after_last_mod],
+        day,
+        &mod_rs[after_last_mod..end],
+        attribute,
+        day,
+        day,
+        &mod_rs[end..]
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MOD_RS: &str = "pub mod day01;
+pub mod day02;
+
+#[derive(Analyzer, AoC)]
+pub struct Days(
+    day01::Day01,
+    day02::Day02,
+);
+";
+
+    #[test]
+    fn register_day() {
+        assert_eq!(
+            register(MOD_RS, 3).unwrap(),
+            "pub mod day01;
+pub mod day02;
+pub mod day03;
+
+#[derive(Analyzer, AoC)]
+pub struct Days(
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+);
+"
+        );
+        assert!(register(MOD_RS, 2).is_err());
+        assert!(register(MOD_RS, 5)
+            .unwrap()
+            .contains("    #[aoc(day = 5)]\n    day05::Day05,\n);"));
+    }
+
+    #[test]
+    fn render_day() {
+        let day = render(7);
+        assert!(day.starts_with("//! # Day 07 <name>"));
+        assert!(day.contains("pub struct Day07("));
+        assert!(!day.contains("00"));
+    }
+}